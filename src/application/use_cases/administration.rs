@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::use_cases::mission_operation::notify_released_crew,
+    domain::{
+        errors::{DomainError, DomainResult},
+        repositories::{administration::AdministrationRepository, sessions::SessionRepository},
+    },
+    infrastructure::mailer::Mailer,
+};
+
+pub struct AdministrationUseCase<T1, T2>
+where
+    T1: AdministrationRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    administration_repository: Arc<T1>,
+    session_repository: Arc<T2>,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl<T1, T2> AdministrationUseCase<T1, T2>
+where
+    T1: AdministrationRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    pub fn new(
+        administration_repository: Arc<T1>,
+        session_repository: Arc<T2>,
+        mailer: Arc<dyn Mailer>,
+    ) -> Self {
+        Self {
+            administration_repository,
+            session_repository,
+            mailer,
+        }
+    }
+
+    /// Open to moderators as well. Cancels the mission like its chief would,
+    /// releasing and mailing the crew.
+    pub async fn force_close_mission(
+        &self,
+        moderator_id: i32,
+        mission_id: i32,
+    ) -> DomainResult<i32> {
+        let cancelled = self
+            .administration_repository
+            .force_close_mission(mission_id, moderator_id)
+            .await?;
+
+        notify_released_crew(self.mailer.as_ref(), &cancelled).await;
+
+        Ok(cancelled.mission.id)
+    }
+
+    /// Unlike a chief, an admin can restore a mission until it is purged.
+    pub async fn restore_mission(&self, admin_id: i32, mission_id: i32) -> DomainResult<i32> {
+        let result = self
+            .administration_repository
+            .restore_mission(mission_id, admin_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn suspend_brawler(&self, admin_id: i32, brawler_id: i32) -> DomainResult<i32> {
+        if admin_id == brawler_id {
+            return Err(DomainError::conflict("Admins cannot suspend themselves"));
+        }
+
+        let result = self
+            .administration_repository
+            .suspend_brawler(brawler_id, Utc::now().naive_utc())
+            .await?;
+
+        // Existing sessions would otherwise keep working until they expire.
+        self.session_repository.revoke_all(brawler_id).await?;
+
+        Ok(result)
+    }
+
+    pub async fn unsuspend_brawler(&self, brawler_id: i32) -> DomainResult<i32> {
+        let result = self
+            .administration_repository
+            .unsuspend_brawler(brawler_id)
+            .await?;
+
+        Ok(result)
+    }
+}
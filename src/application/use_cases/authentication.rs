@@ -1,40 +1,373 @@
 
 use std::{ sync::Arc};
-use anyhow::Result;
 
-use crate::{domain::repositories::brawlers::BrawlerRepository, infrastructure::{self, jwt::{authentication_model::LoginModel, jwt_model::Passport}}};
+use chrono::Utc;
 
-pub struct AuthenticationUseCase<T>
+use crate::{
+    domain::{
+        entities::{refresh_tokens::AddRefreshTokenEntity, sessions::AddSessionEntity},
+        errors::{DomainError, DomainResult},
+        repositories::{
+            brawlers::BrawlerRepository, login_throttles::LoginThrottleRepository,
+            refresh_tokens::RefreshTokenRepository, sessions::SessionRepository,
+            two_factor::TwoFactorRepository,
+        },
+        value_objects::{
+            brawler_roles::BrawlerRoles, login_throttle_policy::LoginThrottlePolicy,
+            session_model::SessionClientModel, two_factor_models::MfaLoginModel,
+        },
+    },
+    application::use_cases::two_factor::verify_second_factor,
+    infrastructure::{
+        self,
+        jwt::{
+            authentication_model::{LoginModel, RefreshTokenModel},
+            jwt_model::{LoginOutcome, MfaChallenge, Passport},
+            refresh_token::{self, RefreshToken},
+        },
+    },
+};
+
+const INVALID_CREDENTIALS: &str = "Invalid username or password";
+const INVALID_VERIFICATION_CODE: &str = "Invalid verification code";
+
+pub struct AuthenticationUseCase<T1, T2, T3, T4, T5>
 where
-    T: BrawlerRepository + Send + Sync {
-    brawler_repository: Arc<T>,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+    T5: TwoFactorRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    refresh_token_repository: Arc<T2>,
+    session_repository: Arc<T3>,
+    login_throttle_repository: Arc<T4>,
+    two_factor_repository: Arc<T5>,
+    login_throttle_policy: LoginThrottlePolicy,
 }
 
 
-impl <T> AuthenticationUseCase<T> 
+impl <T1, T2, T3, T4, T5> AuthenticationUseCase<T1, T2, T3, T4, T5>
     where
-        T: BrawlerRepository + Send + Sync,
+        T1: BrawlerRepository + Send + Sync,
+        T2: RefreshTokenRepository + Send + Sync,
+        T3: SessionRepository + Send + Sync,
+        T4: LoginThrottleRepository + Send + Sync,
+        T5: TwoFactorRepository + Send + Sync,
     {
-        pub fn new(brawler_repository: Arc<T>) -> Self {
-            Self { brawler_repository }
+        pub fn new(
+            brawler_repository: Arc<T1>,
+            refresh_token_repository: Arc<T2>,
+            session_repository: Arc<T3>,
+            login_throttle_repository: Arc<T4>,
+            two_factor_repository: Arc<T5>,
+            login_throttle_policy: LoginThrottlePolicy,
+        ) -> Self {
+            Self {
+                brawler_repository,
+                refresh_token_repository,
+                session_repository,
+                login_throttle_repository,
+                two_factor_repository,
+                login_throttle_policy,
+            }
 
         }
-        pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
+        pub async fn login(
+            &self,
+            login_model: LoginModel,
+            mut session_client: SessionClientModel,
+        ) -> DomainResult<LoginOutcome> {
     let username = login_model.username.clone();
+    let username_key = LoginThrottlePolicy::username_key(&username);
+    let ip_key = session_client
+        .ip_address
+        .as_deref()
+        .map(LoginThrottlePolicy::ip_key);
 
-    let brawler_entity = self.brawler_repository.find_by_username(&username).await?;
+    let throttle_keys: Vec<String> = std::iter::once(username_key.clone())
+        .chain(ip_key.clone())
+        .collect();
+    ensure_not_throttled(
+        self.login_throttle_repository.as_ref(),
+        &self.login_throttle_policy,
+        &throttle_keys,
+    )
+    .await?;
+
+    // Unknown usernames still pay for one hash check and count as a failure,
+    // so neither timing nor the response reveals which usernames exist.
+    let brawler_entity = match self.brawler_repository.find_by_username(&username).await {
+        Ok(brawler_entity) => brawler_entity,
+        Err(_) => {
+            infrastructure::argon2::verify_dummy(login_model.password);
+            return Err(record_failure(
+                self.login_throttle_repository.as_ref(),
+                &self.login_throttle_policy,
+                &throttle_keys,
+                session_client.ip_address.clone(),
+                INVALID_CREDENTIALS,
+            )
+            .await);
+        }
+    };
     let hash_password = brawler_entity.password;
     let login_password = login_model.password;
 
     if !infrastructure::argon2::verify(login_password, hash_password)? {
-        return Err(anyhow::anyhow!("Invalid password!"));
+        return Err(record_failure(
+            self.login_throttle_repository.as_ref(),
+            &self.login_throttle_policy,
+            &throttle_keys,
+            session_client.ip_address.clone(),
+            INVALID_CREDENTIALS,
+        )
+        .await);
+    }
+
+    self.login_throttle_repository.clear(&username_key).await?;
+
+    if brawler_entity.suspended_at.is_some() {
+        return Err(DomainError::forbidden("Account has been suspended"));
+    }
+
+    // With two-factor on, the password only earns a short-lived token for
+    // the second step; no session exists until the code is checked.
+    if brawler_entity.totp_enabled_at.is_some() {
+        let mfa_challenge = MfaChallenge::new(brawler_entity.id)?;
+        return Ok(LoginOutcome::MfaRequired(mfa_challenge));
+    }
+
+    session_client.device_name = login_model.device_name;
+
+    let passport = issue_passport(
+        self.session_repository.as_ref(),
+        brawler_entity.id,
+        brawler_entity.role,
+        session_client,
+    )
+    .await?;
+
+    Ok(LoginOutcome::Passport(passport))
+}
+
+    pub async fn login_mfa(
+        &self,
+        mfa_login_model: MfaLoginModel,
+        mut session_client: SessionClientModel,
+    ) -> DomainResult<Passport> {
+        let brawler_id = MfaChallenge::verify(&mfa_login_model.mfa_token)
+            .map_err(|_| DomainError::unauthorized("Invalid or expired MFA token"))?;
+
+        let throttle_keys = vec![LoginThrottlePolicy::mfa_key(brawler_id)];
+        ensure_not_throttled(
+            self.login_throttle_repository.as_ref(),
+            &self.login_throttle_policy,
+            &throttle_keys,
+        )
+        .await?;
+
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler_entity.suspended_at.is_some() {
+            return Err(DomainError::forbidden("Account has been suspended"));
+        }
+
+        if !verify_second_factor(
+            self.two_factor_repository.as_ref(),
+            &brawler_entity,
+            &mfa_login_model.code,
+        )
+        .await?
+        {
+            return Err(record_failure(
+                self.login_throttle_repository.as_ref(),
+                &self.login_throttle_policy,
+                &throttle_keys,
+                session_client.ip_address.clone(),
+                INVALID_VERIFICATION_CODE,
+            )
+            .await);
+        }
+
+        self.login_throttle_repository.clear(&throttle_keys[0]).await?;
+
+        session_client.device_name = mfa_login_model.device_name;
+
+        let passport = issue_passport(
+            self.session_repository.as_ref(),
+            brawler_entity.id,
+            brawler_entity.role,
+            session_client,
+        )
+        .await?;
+
+        Ok(passport)
+    }
+
+    pub async fn refresh(&self, refresh_token_model: RefreshTokenModel) -> DomainResult<Passport> {
+        let token_hash = refresh_token::hash(&refresh_token_model.refresh_token);
+
+        let current = self
+            .refresh_token_repository
+            .find_by_hash(&token_hash)
+            .await
+            .map_err(|_| DomainError::unauthorized("Invalid refresh token"))?;
+
+        // A revoked token coming back means it was stolen or replayed, so the
+        // whole chain issued from the same login is killed.
+        if current.revoked_at.is_some() {
+            self.session_repository
+                .revoke_by_jti(&current.family_id)
+                .await?;
+            return Err(DomainError::unauthorized("Refresh token has been revoked"));
+        }
+
+        if current.expires_at <= Utc::now().naive_utc() {
+            return Err(DomainError::unauthorized("Refresh token has expired"));
+        }
+
+        // Role and suspension are re-read so changes apply from the next refresh.
+        let brawler_entity = self
+            .brawler_repository
+            .find_by_id(current.brawler_id)
+            .await?;
+        if brawler_entity.suspended_at.is_some() {
+            return Err(DomainError::forbidden("Account has been suspended"));
+        }
+
+        let next = RefreshToken::generate()?;
+        let rotated = self
+            .refresh_token_repository
+            .rotate(
+                current.id,
+                AddRefreshTokenEntity {
+                    brawler_id: current.brawler_id,
+                    token_hash: next.token_hash,
+                    family_id: current.family_id.clone(),
+                    expires_at: next.expires_at,
+                },
+            )
+            .await?;
+
+        if rotated.is_none() {
+            self.session_repository
+                .revoke_by_jti(&current.family_id)
+                .await?;
+            return Err(DomainError::unauthorized("Refresh token has been revoked"));
+        }
+
+        let passport = Passport::new(
+            current.brawler_id,
+            brawler_entity.role,
+            current.family_id,
+            next.token,
+        )?;
+
+        Ok(passport)
     }
 
-    let passport = Passport::new(brawler_entity.id)?;
+    pub async fn logout(&self, refresh_token_model: RefreshTokenModel) -> DomainResult<()> {
+        let token_hash = refresh_token::hash(&refresh_token_model.refresh_token);
+
+        let current = self
+            .refresh_token_repository
+            .find_by_hash(&token_hash)
+            .await
+            .map_err(|_| DomainError::unauthorized("Invalid refresh token"))?;
+
+        self.session_repository
+            .revoke_by_jti(&current.family_id)
+            .await?;
+
+        Ok(())
+    }
+
+    }
+
+/// Opens a session for `brawler_id`; the session `jti` doubles as the refresh
+/// token family so revoking either one ends both.
+pub async fn issue_passport<R>(
+    session_repository: &R,
+    brawler_id: i32,
+    role: BrawlerRoles,
+    session_client: SessionClientModel,
+) -> DomainResult<Passport>
+where
+    R: SessionRepository + Send + Sync,
+{
+    let refresh_token = RefreshToken::generate()?;
+    let jti = refresh_token::generate_family_id();
+
+    session_repository
+        .start(
+            AddSessionEntity {
+                brawler_id,
+                jti: jti.clone(),
+                device_name: session_client.device_name,
+                user_agent: session_client.user_agent,
+                ip_address: session_client.ip_address,
+                expires_at: refresh_token.expires_at,
+            },
+            AddRefreshTokenEntity {
+                brawler_id,
+                token_hash: refresh_token.token_hash,
+                family_id: jti.clone(),
+                expires_at: refresh_token.expires_at,
+            },
+        )
+        .await?;
+
+    let passport = Passport::new(brawler_id, role, jti, refresh_token.token)?;
 
     Ok(passport)
 }
 
+pub async fn ensure_not_throttled<R>(
+    login_throttle_repository: &R,
+    login_throttle_policy: &LoginThrottlePolicy,
+    throttle_keys: &[String],
+) -> DomainResult<()>
+where
+    R: LoginThrottleRepository + Send + Sync,
+{
+    let now = Utc::now().naive_utc();
+
+    let retry_after = login_throttle_repository
+        .find(throttle_keys)
+        .await?
+        .iter()
+        .filter_map(|throttle| login_throttle_policy.retry_after(throttle, now))
+        .max();
+
+    match retry_after {
+        Some(retry_after_seconds) => Err(DomainError::rate_limited(
+            "Too many failed login attempts, try again later",
+            retry_after_seconds,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Counts a failed attempt against every key and returns the error to send back.
+pub async fn record_failure<R>(
+    login_throttle_repository: &R,
+    login_throttle_policy: &LoginThrottlePolicy,
+    throttle_keys: &[String],
+    ip_address: Option<String>,
+    message: &str,
+) -> DomainError
+where
+    R: LoginThrottleRepository + Send + Sync,
+{
+    for throttle_key in throttle_keys {
+        if let Err(e) = login_throttle_repository
+            .record_failure(throttle_key, login_throttle_policy, ip_address.clone())
+            .await
+        {
+            return e.into();
+        }
+    }
 
-    
-    }
\ No newline at end of file
+    DomainError::unauthorized(message)
+}
@@ -1,8 +1,14 @@
 use crate::{
+    application::use_cases::authentication::issue_passport,
     domain::{
-        repositories::brawlers::BrawlerRepository,
+        errors::{DomainError, DomainResult, FieldError},
+        repositories::{brawlers::BrawlerRepository, sessions::SessionRepository},
         value_objects::{
-            base64_image::Base64Image, brawler_model::RegisterBrawlerModel,
+            base64_image::Base64Image,
+            brawler_model::{
+                EditProfileModel, PrivateProfileModel, PublicProfileModel, RegisterBrawlerModel,
+            },
+            brawler_roles::BrawlerRoles, session_model::SessionClientModel,
             uploaded_image::UploadedImage,
         },
     },
@@ -12,46 +18,142 @@ use crate::{
         jwt::jwt_model::Passport,
     },
 };
-use anyhow::Result;
 use std::sync::Arc;
 
-pub struct BrawlersUseCase<T>
+pub struct BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    session_repository: Arc<T2>,
 }
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2> BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(brawler_repository: Arc<T1>, session_repository: Arc<T2>) -> Self {
+        Self {
+            brawler_repository,
+            session_repository,
+        }
     }
 
-    pub async fn register(&self, mut register_model: RegisterBrawlerModel) -> Result<Passport> {
+    pub async fn register(
+        &self,
+        register_model: RegisterBrawlerModel,
+        session_client: SessionClientModel,
+    ) -> DomainResult<Passport> {
+        let avatar = register_model
+            .upload_avatar
+            .clone()
+            .filter(|data| !data.trim().is_empty())
+            .map(Base64Image::new)
+            .transpose();
+
+        // Both checks run first so every field problem is reported together.
+        let (mut register_model, avatar) = match (register_model.validate(), avatar) {
+            (Ok(register_model), Ok(avatar)) => (register_model, avatar),
+            (register_model, avatar) => {
+                let mut fields = match register_model {
+                    Err(DomainError::Validation { fields, .. }) => fields,
+                    Err(e) => return Err(e),
+                    Ok(_) => Vec::new(),
+                };
+                if let Err(e) = avatar {
+                    fields.push(FieldError::new("upload_avatar", e.to_string()));
+                }
+                return Err(DomainError::invalid_fields("Registration is invalid", fields));
+            }
+        };
+
+        // Checked up front for a field-level message; the unique indexes still
+        // turn a concurrent duplicate into a conflict.
+        let mut fields = Vec::new();
+        if self
+            .brawler_repository
+            .username_exists(&register_model.username)
+            .await?
+        {
+            fields.push(FieldError::new("username", "Username is already taken"));
+        }
+        if let Some(email) = register_model.email.as_deref()
+            && self.brawler_repository.email_exists(email).await?
+        {
+            fields.push(FieldError::new("email", "Email is already registered"));
+        }
+        if !fields.is_empty() {
+            return Err(DomainError::invalid_fields("Registration is invalid", fields));
+        }
+
         let hashed_password = hash(register_model.password.clone())?;
 
         register_model.password = hashed_password;
 
         let register_entity = register_model.to_entity();
 
-        let brawler_id = self.brawler_repository.register(register_entity).await?;
+        let brawler_id = match avatar {
+            Some(base64_image) => {
+                let (brawler_id, _) = self
+                    .brawler_repository
+                    .register_with_avatar(register_entity, base64_image, avatar_options(None))
+                    .await?;
+                brawler_id
+            }
+            None => self.brawler_repository.register(register_entity).await?,
+        };
 
-        let passport = Passport::new(brawler_id)?;
+        let passport = issue_passport(
+            self.session_repository.as_ref(),
+            brawler_id,
+            BrawlerRoles::default(),
+            session_client,
+        )
+        .await?;
         Ok(passport)
     }
+    pub async fn my_profile(&self, brawler_id: i32) -> DomainResult<PrivateProfileModel> {
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        let mission_stats = self.brawler_repository.mission_stats(brawler_id).await?;
+
+        Ok(brawler_entity.to_private_profile(mission_stats))
+    }
+
+    /// Suspended brawlers are hidden from other players.
+    pub async fn profile(&self, brawler_id: i32) -> DomainResult<PublicProfileModel> {
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler_entity.suspended_at.is_some() {
+            return Err(DomainError::not_found("Brawler not found"));
+        }
+        let mission_stats = self.brawler_repository.mission_stats(brawler_id).await?;
+
+        Ok(brawler_entity.to_public_profile(mission_stats))
+    }
+
+    pub async fn edit_profile(
+        &self,
+        brawler_id: i32,
+        edit_profile_model: EditProfileModel,
+    ) -> DomainResult<PrivateProfileModel> {
+        let edit_profile_entity = edit_profile_model.to_entity()?;
+
+        let brawler_entity = self
+            .brawler_repository
+            .update_profile(brawler_id, edit_profile_entity)
+            .await?;
+        let mission_stats = self.brawler_repository.mission_stats(brawler_id).await?;
+
+        Ok(brawler_entity.to_private_profile(mission_stats))
+    }
+
     pub async fn upload_avatar(
         &self,
         base64_image: String,
         brawler_id: i32,
-    ) -> Result<UploadedImage> {
-        let option = UploadImageOptions {
-            folder: Some("brawlers_avatar".to_string()),
-            public_id: Some(brawler_id.to_string()),
-            transformation: Some("c_scale,w_256".to_string()),
-        };
+    ) -> DomainResult<UploadedImage> {
+        let option = avatar_options(Some(brawler_id));
 
         let base64_image = Base64Image::new(base64_image)?;
 
@@ -62,4 +164,12 @@ where
 
         Ok(uploaded_image)
     }
-}
\ No newline at end of file
+}
+
+fn avatar_options(brawler_id: Option<i32>) -> UploadImageOptions {
+    UploadImageOptions {
+        folder: Some("brawlers_avatar".to_string()),
+        public_id: brawler_id.map(|brawler_id| brawler_id.to_string()),
+        transformation: Some("c_scale,w_256".to_string()),
+    }
+}
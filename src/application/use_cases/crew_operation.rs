@@ -1,87 +1,80 @@
 use crate::domain::{
-    entities::crew_memberships::CrewMembershipEntity,
-    repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-    },
-    value_objects::mission_statuses::MissionStatuses,
+    entities::{crew_memberships::CrewMembershipEntity, missions::MissionEntity},
+    errors::{DomainError, DomainResult},
+    repositories::crew_operation::CrewOperationRepository,
 };
-use anyhow::Result;
 use std::sync::Arc;
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T>
 where
-    T1: CrewOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: CrewOperationRepository + Send + Sync,
 {
-    crew_operation_repository: Arc<T1>,
-    mission_viewing_repository: Arc<T2>,
+    crew_operation_repository: Arc<T>,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T> CrewOperationUseCase<T>
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T: CrewOperationRepository + Send + Sync + 'static,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(crew_operation_repository: Arc<T>) -> Self {
         Self {
             crew_operation_repository,
-            mission_viewing_repository,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
+    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> DomainResult<()> {
+        // Checked against the locked mission row, so concurrent joins cannot
+        // both take the last slot.
+        let guard = move |mission: &MissionEntity, crew_count: i64| {
+            if mission.chief_id == brawler_id {
+                return Err(DomainError::forbidden(
+                    "Chiefs cannot join their own missions as crew members",
+                ));
+            }
 
-        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+            if !mission.status.accepts_crew_changes() {
+                return Err(DomainError::conflict("Mission is not joinable"));
+            }
+            let crew_count_condition = crew_count < mission.max_crew as i64;
+            if !crew_count_condition {
+                return Err(DomainError::conflict("Mission is full"));
+            }
 
-        if mission.chief_id == brawler_id {
-            return Err(anyhow::anyhow!(
-                "Chiefs cannot join their own missions as crew members"
-            ));
-        }
-
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-
-        let mission_status_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        if !mission_status_condition {
-            return Err(anyhow::anyhow!("Mission is not joinable"));
-        }
-        let crew_count_condition = crew_count < max_crew_per_mission;
-        if !crew_count_condition {
-            return Err(anyhow::anyhow!("Mission is full"));
-        }
+            Ok(())
+        };
 
         self.crew_operation_repository
-            .join(CrewMembershipEntity {
-                mission_id,
-                brawler_id,
-            })
+            .join(
+                CrewMembershipEntity {
+                    mission_id,
+                    brawler_id,
+                },
+                guard,
+            )
             .await?;
 
         Ok(())
     }
 
-    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> DomainResult<()> {
+        let guard = |mission: &MissionEntity, _crew_count: i64| {
+            if !mission.status.accepts_crew_changes() {
+                return Err(DomainError::conflict("Mission is not leavable"));
+            }
+
+            Ok(())
+        };
 
-        let leaving_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        if !leaving_condition {
-            return Err(anyhow::anyhow!("Mission is not leavable"));
-        }
         self.crew_operation_repository
-            .leave(CrewMembershipEntity {
-                mission_id,
-                brawler_id,
-            })
+            .leave(
+                CrewMembershipEntity {
+                    mission_id,
+                    brawler_id,
+                },
+                guard,
+            )
             .await?;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
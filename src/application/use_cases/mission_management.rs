@@ -1,44 +1,71 @@
 use std::sync::Arc;
 
+use chrono::Utc;
+
 use crate::{domain::{
-    repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+    entities::missions::MissionEntity,
+    errors::{DomainError, DomainResult},
+    repositories::mission_management::MissionManagementRepository,
+    value_objects::{
+        brawler_roles::BrawlerRoles,
+        crew_bounds::CrewBounds,
+        mission_model::{AddMissionModel, EditMissionModel, TrashedMissionModel},
+        mission_retention::MissionRetention,
+        mission_schedule::{MissionSchedule, MissionScheduleChanges},
+        mission_statuses::MissionStatuses,
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
 }};
 
-pub struct MissionManagementUseCase<T1, T2>
+pub struct MissionManagementUseCase<T>
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionManagementRepository + Send + Sync,
 {
-    mission_management_repository: Arc<T1>,
-    mission_viewing_repository: Arc<T2>,
+    mission_management_repository: Arc<T>,
+    crew_bounds: CrewBounds,
+    mission_retention: MissionRetention,
 }
 
-use anyhow::Result;
-impl<T1, T2> MissionManagementUseCase<T1, T2>
+impl<T> MissionManagementUseCase<T>
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionManagementRepository + Send + Sync,
 {
     pub fn new(
-        mission_management_repository: Arc<T1>,
-        mission_viewing_repository: Arc<T2>,
+        mission_management_repository: Arc<T>,
+        crew_bounds: CrewBounds,
+        mission_retention: MissionRetention,
     ) -> Self {
         Self {
             mission_management_repository,
-            mission_viewing_repository,
+            crew_bounds,
+            mission_retention,
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
+    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> DomainResult<i32> {
         
         if add_mission_model.name.trim().is_empty() || add_mission_model.name.trim().len()<3 {
-            return Err(anyhow::anyhow!("Mission name must be at least 3 characters long!"));
+            return Err(DomainError::validation("Mission name must be at least 3 characters long!"));
         }
 
-        let insert_mission_entity = add_mission_model.to_entity(chief_id);
+        let insert_mission_entity = add_mission_model.to_entity(chief_id, &self.crew_bounds);
+
+        let fields = self
+            .crew_bounds
+            .check(insert_mission_entity.min_crew, insert_mission_entity.max_crew);
+        if !fields.is_empty() {
+            return Err(DomainError::invalid_fields("Crew size is invalid", fields));
+        }
+
+        let fields = MissionSchedule::default().check_changes(
+            &MissionScheduleChanges::from(MissionSchedule {
+                starts_at: insert_mission_entity.starts_at,
+                deadline: insert_mission_entity.deadline,
+            }),
+            Utc::now().naive_utc(),
+        );
+        if !fields.is_empty() {
+            return Err(DomainError::invalid_fields("Schedule is invalid", fields));
+        }
 
         let result = self
             .mission_management_repository
@@ -53,52 +80,149 @@ where
         mission_id: i32,
         chief_id: i32,
         mut edit_mission_model: EditMissionModel,
-    ) -> Result<i32> {
+    ) -> DomainResult<i32> {
 
         if let Some(name) = edit_mission_model.name {
             if name.trim().is_empty() {
                 edit_mission_model.name = None;
             }else if name.trim().len()<3 {
-                return Err(anyhow::anyhow!("Mission name must be at least 3 characters long!"));
+                return Err(DomainError::validation("Mission name must be at least 3 characters long!"));
             }else{
                 edit_mission_model.name = Some(name.trim().to_string());
             }
         }
 
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-        if crew_count > 0 {
-            return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
-            ));
+        let edit_mission_entity = edit_mission_model.to_entity();
+        if edit_mission_entity.is_empty() {
+            return Err(DomainError::validation("Nothing to update"));
         }
 
-        let edit_mission_entity = edit_mission_model.to_entity(chief_id);
+        // Checked against the locked row, so a join or another edit cannot
+        // slip in between the checks and the update.
+        let crew_bounds = self.crew_bounds;
+        let (min_crew, max_crew) = (edit_mission_model.min_crew, edit_mission_model.max_crew);
+        let schedule_changes = MissionScheduleChanges {
+            starts_at: edit_mission_model.starts_at,
+            deadline: edit_mission_model.deadline,
+        };
+        let guard = move |mission: &MissionEntity, crew_count: i64| {
+            ensure_changeable(mission, chief_id, crew_count)?;
+
+            if min_crew.is_some() || max_crew.is_some() {
+                let fields = crew_bounds.check(
+                    min_crew.unwrap_or(mission.min_crew),
+                    max_crew.unwrap_or(mission.max_crew),
+                );
+                if !fields.is_empty() {
+                    return Err(DomainError::invalid_fields("Crew size is invalid", fields));
+                }
+            }
+
+            let current_schedule = MissionSchedule {
+                starts_at: mission.starts_at,
+                deadline: mission.deadline,
+            };
+            let fields = current_schedule.check_changes(&schedule_changes, Utc::now().naive_utc());
+            if !fields.is_empty() {
+                return Err(DomainError::invalid_fields("Schedule is invalid", fields));
+            }
+
+            Ok(())
+        };
 
         let result = self
             .mission_management_repository
-            .edit(mission_id, edit_mission_entity)
+            .edit(mission_id, chief_id, edit_mission_entity, guard)
             .await?;
 
         Ok(result)
     }
 
-    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-        if crew_count > 0 {
-            return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
-            ));
-        }
-
+    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> DomainResult<()> {
         self.mission_management_repository
-            .remove(mission_id, chief_id)
+            .remove(
+                mission_id,
+                chief_id,
+                Utc::now().naive_utc(),
+                move |mission: &MissionEntity, crew_count: i64| {
+                    ensure_changeable(mission, chief_id, crew_count)
+                },
+            )
             .await?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Admins see every removed mission, chiefs only their own.
+    pub async fn trash(
+        &self,
+        brawler_id: i32,
+        role: BrawlerRoles,
+    ) -> DomainResult<Vec<TrashedMissionModel>> {
+        let chief_id = match role {
+            BrawlerRoles::Admin => None,
+            _ => Some(brawler_id),
+        };
+
+        let missions = self
+            .mission_management_repository
+            .get_trash(chief_id)
+            .await?;
+
+        Ok(missions
+            .iter()
+            .filter_map(|mission| mission.to_trashed_model(&self.mission_retention))
+            .collect())
+    }
+
+    /// Undoes `remove` while the restore window is open.
+    pub async fn restore(&self, mission_id: i32, chief_id: i32) -> DomainResult<i32> {
+        let mission_retention = self.mission_retention;
+        let guard = move |mission: &MissionEntity, _crew_count: i64| {
+            if mission.chief_id != chief_id {
+                return Err(DomainError::forbidden(
+                    "Only the chief can restore this mission",
+                ));
+            }
+
+            let restorable = mission.deleted_at.is_some_and(|deleted_at| {
+                Utc::now().naive_utc() <= mission_retention.restorable_until(deleted_at)
+            });
+            if !restorable {
+                return Err(DomainError::conflict(
+                    "The restore window for this mission has closed",
+                ));
+            }
+
+            Ok(())
+        };
+
+        let result = self
+            .mission_management_repository
+            .restore(mission_id, chief_id, guard)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+/// Only the chief may change a mission, and only while it is open and nobody
+/// has joined yet.
+fn ensure_changeable(mission: &MissionEntity, chief_id: i32, crew_count: i64) -> DomainResult<()> {
+    if mission.chief_id != chief_id {
+        return Err(DomainError::forbidden(
+            "Only the chief can change this mission",
+        ));
+    }
+    if mission.status != MissionStatuses::Open {
+        return Err(DomainError::conflict(
+            "Mission can only be changed while it is open",
+        ));
+    }
+    if crew_count > 0 {
+        return Err(DomainError::conflict(
+            "Mission has been taken by brawler for now!",
+        ));
+    }
+
+    Ok(())
+}
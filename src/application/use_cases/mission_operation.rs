@@ -1,91 +1,322 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use chrono::Utc;
+use tracing::error;
 
-use crate::domain::{
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    domain::{
+        entities::{
+            mission_co_chiefs::MissionCoChiefEntity,
+            missions::{CancelledMissionEntity, MissionEntity},
+        },
+        errors::{DomainError, DomainResult},
+        repositories::mission_operation::MissionOperationRepository,
+        value_objects::{
+            mission_model::{CoChiefModel, GrantCoChiefModel, TransferChiefModel},
+            mission_permissions::MissionPermissions,
+            mission_statuses::MissionStatuses,
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
+    infrastructure::mailer::{MailMessage, Mailer},
 };
-pub struct MissionOperationUseCase<T1, T2>
+pub struct MissionOperationUseCase<T>
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionOperationRepository + Send + Sync,
 {
-    mission_operation_repository: Arc<T1>,
-    missiom_viewing_repository: Arc<T2>,
+    mission_operation_repository: Arc<T>,
+    mailer: Arc<dyn Mailer>,
 }
 
-impl<T1, T2> MissionOperationUseCase<T1, T2>
+impl<T> MissionOperationUseCase<T>
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionOperationRepository + Send + Sync,
 {
-    pub fn new(mission_operation_repository: Arc<T1>, missiom_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(mission_operation_repository: Arc<T>, mailer: Arc<dyn Mailer>) -> Self {
         Self {
             mission_operation_repository,
-            missiom_viewing_repository,
+            mailer,
         }
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
+    pub async fn in_progress(&self, mission_id: i32, actor_id: i32) -> DomainResult<i32> {
+        // Runs with the mission row locked, so no join or leave can change the
+        // crew between this check and the status update. The repository checks
+        // the transition itself for every status change.
+        let guard = move |mission: &MissionEntity,
+                          crew_count: i64,
+                          co_chief: Option<&MissionCoChiefEntity>| {
+            ensure_permitted(mission, co_chief, actor_id, MissionPermissions::Start)?;
+            mission.status.transition(MissionStatuses::InProgress)?;
 
-        let crew_count = self
-            .missiom_viewing_repository
-            .crew_counting(mission_id)
+            let crew_size_condition =
+                crew_count >= mission.min_crew as i64 && crew_count <= mission.max_crew as i64;
+            if !crew_size_condition {
+                return Err(DomainError::conflict(format!(
+                    "Mission needs between {} and {} crew members to start",
+                    mission.min_crew, mission.max_crew
+                )));
+            }
+
+            Ok(())
+        };
+
+        let result = self
+            .mission_operation_repository
+            .to_progress(mission_id, Some(actor_id), guard)
+            .await?;
+        Ok(result)
+    }
+    pub async fn to_completed(&self, mission_id: i32, actor_id: i32) -> DomainResult<i32> {
+        let result = self
+            .mission_operation_repository
+            .to_completed(
+                mission_id,
+                Some(actor_id),
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_permitted(mission, co_chief, actor_id, MissionPermissions::Complete)
+                },
+            )
+            .await?;
+
+        Ok(result)
+    }
+    pub async fn to_failed(&self, mission_id: i32, actor_id: i32) -> DomainResult<i32> {
+        let result = self
+            .mission_operation_repository
+            .to_failed(
+                mission_id,
+                Some(actor_id),
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_permitted(mission, co_chief, actor_id, MissionPermissions::Fail)
+                },
+            )
             .await?;
 
-        let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
+        Ok(result)
+    }
+
+    /// Releases the crew and mails everyone who had an address on file.
+    pub async fn to_cancelled(&self, mission_id: i32, chief_id: i32) -> DomainResult<i32> {
+        let cancelled = self
+            .mission_operation_repository
+            .to_cancelled(
+                mission_id,
+                Some(chief_id),
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      _co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_chief(mission, chief_id)
+                },
+            )
+            .await?;
 
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
+        notify_released_crew(self.mailer.as_ref(), &cancelled).await;
 
-        let update_condition = is_status_open_or_fail
-            && crew_count > 0
-            && crew_count < max_crew_per_mission
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+        Ok(cancelled.mission.id)
+    }
 
+    pub async fn to_archived(&self, mission_id: i32, chief_id: i32) -> DomainResult<i32> {
         let result = self
             .mission_operation_repository
-            .to_progress(mission_id, chief_id)
+            .to_archived(
+                mission_id,
+                Some(chief_id),
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      _co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_chief(mission, chief_id)
+                },
+            )
             .await?;
+
         Ok(result)
     }
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
+    /// Fails every in-progress mission past its deadline, as the server
+    /// rather than a brawler. Each one goes through the same transition rules
+    /// as `to_failed`; a mission that changed in the meantime is skipped.
+    pub async fn expire_overdue(&self) -> DomainResult<usize> {
+        let now = Utc::now().naive_utc();
+        let overdue = self
+            .mission_operation_repository
+            .overdue_missions(now)
+            .await?;
+
+        let mut expired = 0;
+        for mission_id in overdue {
+            // Checked again under the lock, since the chief may have finished
+            // or rescheduled the mission since the lookup.
+            let guard = move |mission: &MissionEntity,
+                              _crew_count: i64,
+                              _co_chief: Option<&MissionCoChiefEntity>| {
+                let still_overdue = mission.status == MissionStatuses::InProgress
+                    && mission.deadline.is_some_and(|deadline| deadline < now);
+                if !still_overdue {
+                    return Err(DomainError::conflict("Mission is no longer overdue"));
+                }
+
+                Ok(())
+            };
+
+            match self
+                .mission_operation_repository
+                .to_failed(mission_id, None, guard)
+                .await
+                .map_err(DomainError::from)
+            {
+                Ok(_) => expired += 1,
+                Err(DomainError::Conflict(_)) | Err(DomainError::NotFound(_)) => {}
+                Err(e) => error!("Failed to expire mission({}): {}", mission_id, e),
+            }
+        }
+
+        Ok(expired)
+    }
+
+    pub async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transfer: TransferChiefModel,
+    ) -> DomainResult<i32> {
+        if transfer.new_chief_id == chief_id {
+            return Err(DomainError::validation("You are already the chief"));
         }
+
         let result = self
             .mission_operation_repository
-            .to_completed(mission_id, chief_id)
+            .transfer_chief(
+                mission_id,
+                chief_id,
+                transfer.new_chief_id,
+                transfer.stay_in_crew.unwrap_or(true),
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      _co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_leadership_change(mission, chief_id)
+                },
+            )
             .await?;
 
         Ok(result)
     }
-    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+    pub async fn grant_co_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        brawler_id: i32,
+        grant: GrantCoChiefModel,
+    ) -> DomainResult<CoChiefModel> {
         let result = self
             .mission_operation_repository
-            .to_failed(mission_id, chief_id)
+            .grant_co_chief(
+                chief_id,
+                grant.to_entity(mission_id, brawler_id),
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      _co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_leadership_change(mission, chief_id)
+                },
+            )
             .await?;
 
-        Ok(result)
+        Ok(result.to_model())
+    }
+
+    pub async fn revoke_co_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        brawler_id: i32,
+    ) -> DomainResult<()> {
+        self.mission_operation_repository
+            .revoke_co_chief(
+                mission_id,
+                chief_id,
+                brawler_id,
+                move |mission: &MissionEntity,
+                      _crew_count: i64,
+                      _co_chief: Option<&MissionCoChiefEntity>| {
+                    ensure_leadership_change(mission, chief_id)
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// The cancellation is already committed, so a mail that fails is only
+/// logged.
+pub(crate) async fn notify_released_crew(mailer: &dyn Mailer, cancelled: &CancelledMissionEntity) {
+    for brawler in &cancelled.released_crew {
+        let Some(email) = &brawler.email else {
+            continue;
+        };
+
+        let mail_message = MailMessage {
+            to: email.clone(),
+            subject: format!("Mission \"{}\" was cancelled", cancelled.mission.name),
+            body: format!(
+                "Hi {},\n\nThe mission \"{}\" has been cancelled, so you are no longer part of its crew and are free to join another one.",
+                brawler.display_name, cancelled.mission.name,
+            ),
+        };
+
+        if let Err(e) = mailer.send(mail_message).await {
+            error!("Failed to send mission cancellation mail: {}", e);
+        }
     }
-}
\ No newline at end of file
+}
+
+/// The chief may always change stages; a co-chief only with the delegated
+/// permission.
+fn ensure_permitted(
+    mission: &MissionEntity,
+    co_chief: Option<&MissionCoChiefEntity>,
+    actor_id: i32,
+    permission: MissionPermissions,
+) -> DomainResult<()> {
+    if mission.chief_id == actor_id {
+        return Ok(());
+    }
+
+    match co_chief {
+        Some(co_chief) if co_chief.allows(permission) => Ok(()),
+        _ => Err(DomainError::forbidden(format!(
+            "Only the chief or a co-chief allowed to {} can change stages!",
+            permission
+        ))),
+    }
+}
+
+/// Only the chief hands over or delegates leadership, and only while the
+/// mission is still running.
+fn ensure_leadership_change(mission: &MissionEntity, chief_id: i32) -> DomainResult<()> {
+    if mission.chief_id != chief_id {
+        return Err(DomainError::forbidden(
+            "Only the chief can change the mission's leadership",
+        ));
+    }
+    if mission.status.is_finished() {
+        return Err(DomainError::conflict(
+            "Leadership of a finished mission cannot change",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Cancelling and archiving are never delegated.
+fn ensure_chief(mission: &MissionEntity, chief_id: i32) -> DomainResult<()> {
+    if mission.chief_id != chief_id {
+        return Err(DomainError::forbidden("Only the chief can change stages!"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::{
+    errors::DomainResult, repositories::mission_management::MissionManagementRepository,
+    value_objects::mission_retention::MissionRetention,
+};
+
+pub struct MissionPurgeUseCase<T>
+where
+    T: MissionManagementRepository + Send + Sync,
+{
+    mission_management_repository: Arc<T>,
+    mission_retention: MissionRetention,
+}
+
+impl<T> MissionPurgeUseCase<T>
+where
+    T: MissionManagementRepository + Send + Sync,
+{
+    pub fn new(mission_management_repository: Arc<T>, mission_retention: MissionRetention) -> Self {
+        Self {
+            mission_management_repository,
+            mission_retention,
+        }
+    }
+
+    /// Returns how many missions were deleted for good.
+    pub async fn purge_expired(&self) -> DomainResult<usize> {
+        let cutoff = self
+            .mission_retention
+            .purge_cutoff(Utc::now().naive_utc());
+
+        let result = self
+            .mission_management_repository
+            .purge_removed(cutoff)
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -1,10 +1,15 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use chrono::Utc;
 
 use crate::domain::{
+    errors::{DomainError, DomainResult},
     repositories::mission_viewing::MissionViewingRepository,
-    value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel},
+    value_objects::{
+        brawler_model::CrewMemberModel,
+        mission_filter::{MissionCursor, MissionFilter, MissionViewer},
+        mission_model::{CoChiefModel, MissionEventModel, MissionModel, MissionPageModel},
+    },
 };
 pub struct MissionViewingUseCase<T>
 where
@@ -23,7 +28,7 @@ where
         }
     }
 
-    pub async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
+    pub async fn get_one(&self, mission_id: i32) -> DomainResult<MissionModel> {
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
@@ -36,30 +41,90 @@ where
         Ok(result)
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let models = self.mission_viewing_repository.get_all(filter).await?;
+    pub async fn get_all(
+        &self,
+        filter: &MissionFilter,
+        viewer_id: Option<i32>,
+    ) -> DomainResult<MissionPageModel> {
+        let page = filter.page_request()?;
 
-        let mut result = Vec::new();
+        if filter.needs_viewer() && viewer_id.is_none() {
+            return Err(DomainError::unauthorized(
+                "Sign in to filter missions by your own crew or chief roles",
+            ));
+        }
 
-        for model in models.into_iter() {
-            let crew_count = self
-                .mission_viewing_repository
-                .crew_counting(model.id)
-                .await
-                .unwrap_or(0);
+        let viewer = MissionViewer {
+            brawler_id: viewer_id,
+            now: Utc::now().naive_utc(),
+        };
 
-            result.push(model.to_model(crew_count));
-        }
+        let mut models = self
+            .mission_viewing_repository
+            .get_all(filter, &viewer, &page)
+            .await?;
+
+        let has_more = models.len() as i64 > page.limit;
+        models.truncate(page.limit as usize);
+
+        let items: Vec<MissionModel> = models
+            .into_iter()
+            .map(|listing| listing.to_model())
+            .collect();
+
+        let next_cursor = match items.last() {
+            Some(last) if has_more => {
+                Some(MissionCursor::after(last, page.sort, page.direction).encode())
+            }
+            _ => None,
+        };
+
+        let total = if page.include_total {
+            Some(
+                self.mission_viewing_repository
+                    .count_all(filter, &viewer, &page)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        Ok(MissionPageModel {
+            items,
+            next_cursor,
+            total,
+        })
+    }
+
+    pub async fn get_crew(&self, mission_id: i32) -> DomainResult<Vec<CrewMemberModel>> {
+        // Fails with not found for a missing or deleted mission instead of an
+        // empty roster.
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let result = self.mission_viewing_repository.get_crew(mission_id).await?;
 
         Ok(result)
     }
-    
-    pub async fn get_mission_count(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
-        let result = self
+
+    pub async fn get_history(&self, mission_id: i32) -> DomainResult<Vec<MissionEventModel>> {
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let events = self
             .mission_viewing_repository
-            .get_mission_count(mission_id)
+            .get_history(mission_id)
             .await?;
 
-        Ok(result)
+        Ok(events.iter().map(|event| event.to_model()).collect())
     }
-}
\ No newline at end of file
+
+    pub async fn get_co_chiefs(&self, mission_id: i32) -> DomainResult<Vec<CoChiefModel>> {
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let co_chiefs = self
+            .mission_viewing_repository
+            .get_co_chiefs(mission_id)
+            .await?;
+
+        Ok(co_chiefs.iter().map(|co_chief| co_chief.to_model()).collect())
+    }
+}
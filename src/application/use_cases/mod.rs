@@ -1,7 +1,12 @@
+pub mod administration;
 pub mod authentication;
 pub mod brawlers;
 pub mod crew_operation;
 pub mod mission_management;
 pub mod mission_operation;
+pub mod mission_purge;
 pub mod mission_viewing;
+pub mod passwords;
+pub mod sessions;
+pub mod two_factor;
 
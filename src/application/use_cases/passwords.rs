@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use tracing::error;
+
+use crate::{
+    application::use_cases::authentication::{ensure_not_throttled, record_failure},
+    config::config_loader::get_password_reset_env,
+    domain::{
+        entities::{brawlers::BrawlerEntity, password_reset_tokens::AddPasswordResetTokenEntity},
+        errors::{DomainError, DomainResult},
+        repositories::{
+            brawlers::BrawlerRepository, login_throttles::LoginThrottleRepository,
+            password_resets::PasswordResetRepository, sessions::SessionRepository,
+        },
+        value_objects::{
+            login_throttle_policy::LoginThrottlePolicy,
+            password_models::{ChangePasswordModel, ForgotPasswordModel, ResetPasswordModel},
+            password_policy,
+            session_model::SessionClientModel,
+        },
+    },
+    infrastructure::{
+        argon2::{hash, verify},
+        jwt::{password_reset_token::PasswordResetToken, refresh_token},
+        mailer::{MailMessage, Mailer},
+    },
+};
+
+pub struct PasswordsUseCase<T1, T2, T3, T4>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    password_reset_repository: Arc<T2>,
+    session_repository: Arc<T3>,
+    login_throttle_repository: Arc<T4>,
+    login_throttle_policy: LoginThrottlePolicy,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl<T1, T2, T3, T4> PasswordsUseCase<T1, T2, T3, T4>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync + 'static,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        password_reset_repository: Arc<T2>,
+        session_repository: Arc<T3>,
+        login_throttle_repository: Arc<T4>,
+        login_throttle_policy: LoginThrottlePolicy,
+        mailer: Arc<dyn Mailer>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            password_reset_repository,
+            session_repository,
+            login_throttle_repository,
+            login_throttle_policy,
+            mailer,
+        }
+    }
+
+    /// Every other session is signed out; the one making the change stays.
+    /// Wrong current passwords count against the same throttle as logins.
+    pub async fn change_password(
+        &self,
+        brawler_id: i32,
+        current_jti: &str,
+        session_client: SessionClientModel,
+        change_password_model: ChangePasswordModel,
+    ) -> DomainResult<()> {
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+
+        let throttle_keys = vec![LoginThrottlePolicy::username_key(&brawler_entity.username)];
+        ensure_not_throttled(
+            self.login_throttle_repository.as_ref(),
+            &self.login_throttle_policy,
+            &throttle_keys,
+        )
+        .await?;
+
+        if !verify(change_password_model.current_password, brawler_entity.password)? {
+            return Err(record_failure(
+                self.login_throttle_repository.as_ref(),
+                &self.login_throttle_policy,
+                &throttle_keys,
+                session_client.ip_address,
+                "Current password is incorrect",
+            )
+            .await);
+        }
+        self.login_throttle_repository.clear(&throttle_keys[0]).await?;
+
+        password_policy::validate_password(
+            "new_password",
+            &change_password_model.new_password,
+            &brawler_entity.username,
+        )
+        .map_err(|e| DomainError::invalid_fields("Password is invalid", vec![e]))?;
+
+        let hashed_password = hash(change_password_model.new_password)?;
+        self.brawler_repository
+            .update_password(brawler_id, hashed_password)
+            .await?;
+
+        self.session_repository
+            .revoke_others(brawler_id, current_jti)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Always succeeds so the response does not reveal which emails are
+    /// registered. The token and mail are issued in the background so known
+    /// and unknown emails also take the same time to answer.
+    pub async fn forgot_password(&self, forgot_password_model: ForgotPasswordModel) -> DomainResult<()> {
+        let brawler_entity = match self
+            .brawler_repository
+            .find_by_email(&forgot_password_model.email)
+            .await
+        {
+            Ok(brawler_entity) => brawler_entity,
+            Err(_) => return Ok(()),
+        };
+
+        let password_reset_repository = Arc::clone(&self.password_reset_repository);
+        let mailer = Arc::clone(&self.mailer);
+        tokio::spawn(async move {
+            if let Err(e) =
+                send_reset_mail(password_reset_repository.as_ref(), mailer.as_ref(), brawler_entity)
+                    .await
+            {
+                error!("Failed to send password reset mail: {}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    pub async fn reset_password(&self, reset_password_model: ResetPasswordModel) -> DomainResult<()> {
+        // The username is unknown until the token is consumed, so only the
+        // length and common-password rules apply here.
+        password_policy::validate_password("new_password", &reset_password_model.new_password, "")
+            .map_err(|e| DomainError::invalid_fields("Password is invalid", vec![e]))?;
+
+        let token_hash = refresh_token::hash(&reset_password_model.token);
+        let hashed_password = hash(reset_password_model.new_password)?;
+
+        let brawler_id = self
+            .password_reset_repository
+            .consume(&token_hash, hashed_password)
+            .await?
+            .ok_or_else(|| DomainError::unauthorized("Reset token is invalid or has expired"))?;
+
+        self.session_repository.revoke_all(brawler_id).await?;
+
+        Ok(())
+    }
+}
+
+async fn send_reset_mail<R>(
+    password_reset_repository: &R,
+    mailer: &dyn Mailer,
+    brawler_entity: BrawlerEntity,
+) -> anyhow::Result<()>
+where
+    R: PasswordResetRepository + Send + Sync,
+{
+    let Some(email) = brawler_entity.email else {
+        return Ok(());
+    };
+
+    let password_reset_env = get_password_reset_env()?;
+    let reset_token = PasswordResetToken::generate()?;
+
+    password_reset_repository
+        .add(AddPasswordResetTokenEntity {
+            brawler_id: brawler_entity.id,
+            token_hash: reset_token.token_hash,
+            expires_at: reset_token.expires_at,
+        })
+        .await?;
+
+    let mail_message = MailMessage {
+        to: email,
+        subject: "Reset your password".to_string(),
+        body: format!(
+            "Hi {},\n\nUse the link below to choose a new password. It expires in {} minutes.\n\n{}?token={}\n\nIf you did not ask for this, you can ignore this mail.",
+            brawler_entity.display_name,
+            password_reset_env.lift_time_minutes,
+            password_reset_env.url,
+            reset_token.token,
+        ),
+    };
+
+    mailer.send(mail_message).await
+}
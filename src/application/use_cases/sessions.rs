@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    errors::DomainResult, repositories::sessions::SessionRepository,
+    value_objects::session_model::SessionModel,
+};
+
+pub struct SessionsUseCase<T>
+where
+    T: SessionRepository + Send + Sync,
+{
+    session_repository: Arc<T>,
+}
+
+impl<T> SessionsUseCase<T>
+where
+    T: SessionRepository + Send + Sync,
+{
+    pub fn new(session_repository: Arc<T>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn list_active(
+        &self,
+        brawler_id: i32,
+        current_jti: &str,
+    ) -> DomainResult<Vec<SessionModel>> {
+        let sessions = self.session_repository.list_active(brawler_id).await?;
+
+        let result = sessions
+            .iter()
+            .map(|session| session.to_model(current_jti))
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn revoke(&self, brawler_id: i32, session_id: i32) -> DomainResult<()> {
+        self.session_repository
+            .revoke(brawler_id, session_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_all(&self, brawler_id: i32) -> DomainResult<()> {
+        self.session_repository.revoke_all(brawler_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::authentication::{ensure_not_throttled, record_failure},
+    config::config_loader::get_totp_env,
+    domain::{
+        entities::brawlers::BrawlerEntity,
+        errors::{DomainError, DomainResult},
+        repositories::{
+            brawlers::BrawlerRepository, login_throttles::LoginThrottleRepository,
+            two_factor::TwoFactorRepository,
+        },
+        value_objects::{
+            login_throttle_policy::LoginThrottlePolicy,
+            two_factor_models::{
+                DisableTwoFactorModel, RecoveryCodesModel, TotpCodeModel, TotpEnrollmentModel,
+            },
+        },
+    },
+    infrastructure::{argon2, totp},
+};
+
+const INVALID_VERIFICATION_CODE: &str = "Invalid verification code";
+
+pub struct TwoFactorUseCase<T1, T2, T3>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: TwoFactorRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    two_factor_repository: Arc<T2>,
+    login_throttle_repository: Arc<T3>,
+    login_throttle_policy: LoginThrottlePolicy,
+}
+
+impl<T1, T2, T3> TwoFactorUseCase<T1, T2, T3>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: TwoFactorRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        two_factor_repository: Arc<T2>,
+        login_throttle_repository: Arc<T3>,
+        login_throttle_policy: LoginThrottlePolicy,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            two_factor_repository,
+            login_throttle_repository,
+            login_throttle_policy,
+        }
+    }
+
+    /// Starting again before confirming simply replaces the pending secret.
+    pub async fn enroll(&self, brawler_id: i32) -> DomainResult<TotpEnrollmentModel> {
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler_entity.totp_enabled_at.is_some() {
+            return Err(DomainError::conflict("Two-factor authentication is already enabled"));
+        }
+
+        let secret = totp::generate_secret();
+        let otpauth_uri =
+            totp::otpauth_uri(&secret, &get_totp_env().issuer, &brawler_entity.username)?;
+
+        self.two_factor_repository
+            .set_pending_secret(brawler_id, secret.clone())
+            .await?;
+
+        Ok(TotpEnrollmentModel {
+            secret,
+            otpauth_uri,
+        })
+    }
+
+    /// Wrong codes count against the same `mfa:{id}` key as the login step.
+    pub async fn confirm(
+        &self,
+        brawler_id: i32,
+        totp_code_model: TotpCodeModel,
+        ip_address: Option<String>,
+    ) -> DomainResult<RecoveryCodesModel> {
+        let throttle_keys = vec![LoginThrottlePolicy::mfa_key(brawler_id)];
+        self.ensure_not_throttled(&throttle_keys).await?;
+
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler_entity.totp_enabled_at.is_some() {
+            return Err(DomainError::conflict("Two-factor authentication is already enabled"));
+        }
+        let secret = brawler_entity
+            .totp_secret
+            .ok_or_else(|| DomainError::conflict("Two-factor enrollment has not been started"))?;
+
+        let Some(totp_step) = totp::verify(&secret, &totp_code_model.code)? else {
+            return Err(self.record_failure(&throttle_keys, ip_address).await);
+        };
+
+        let (recovery_codes, recovery_code_hashes) = generate_recovery_codes()?;
+        self.two_factor_repository
+            .enable(brawler_id, totp_step, recovery_code_hashes)
+            .await?;
+        self.login_throttle_repository.clear(&throttle_keys[0]).await?;
+
+        Ok(RecoveryCodesModel { recovery_codes })
+    }
+
+    pub async fn disable(
+        &self,
+        brawler_id: i32,
+        disable_two_factor_model: DisableTwoFactorModel,
+        ip_address: Option<String>,
+    ) -> DomainResult<()> {
+        let throttle_keys = vec![LoginThrottlePolicy::mfa_key(brawler_id)];
+        self.ensure_not_throttled(&throttle_keys).await?;
+
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler_entity.totp_enabled_at.is_none() {
+            return Err(DomainError::conflict("Two-factor authentication is not enabled"));
+        }
+
+        // A wrong password counts against the same throttle as a wrong code and
+        // gets the same answer, so neither factor can be guessed on its own.
+        if !argon2::verify(disable_two_factor_model.password, brawler_entity.password.clone())? {
+            return Err(self.record_failure(&throttle_keys, ip_address).await);
+        }
+        if !verify_second_factor(
+            self.two_factor_repository.as_ref(),
+            &brawler_entity,
+            &disable_two_factor_model.code,
+        )
+        .await?
+        {
+            return Err(self.record_failure(&throttle_keys, ip_address).await);
+        }
+
+        self.two_factor_repository.disable(brawler_id).await?;
+        self.login_throttle_repository.clear(&throttle_keys[0]).await?;
+
+        Ok(())
+    }
+
+    /// Needs a TOTP code; a recovery code cannot be used to mint new ones.
+    /// Wrong codes are throttled like in `confirm`.
+    pub async fn regenerate_recovery_codes(
+        &self,
+        brawler_id: i32,
+        totp_code_model: TotpCodeModel,
+        ip_address: Option<String>,
+    ) -> DomainResult<RecoveryCodesModel> {
+        let throttle_keys = vec![LoginThrottlePolicy::mfa_key(brawler_id)];
+        self.ensure_not_throttled(&throttle_keys).await?;
+
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler_entity.totp_enabled_at.is_none() {
+            return Err(DomainError::conflict("Two-factor authentication is not enabled"));
+        }
+
+        if !totp::is_totp_code(&totp_code_model.code)
+            || !verify_second_factor(
+                self.two_factor_repository.as_ref(),
+                &brawler_entity,
+                &totp_code_model.code,
+            )
+            .await?
+        {
+            return Err(self.record_failure(&throttle_keys, ip_address).await);
+        }
+
+        let (recovery_codes, recovery_code_hashes) = generate_recovery_codes()?;
+        self.two_factor_repository
+            .replace_recovery_codes(brawler_id, recovery_code_hashes)
+            .await?;
+        self.login_throttle_repository.clear(&throttle_keys[0]).await?;
+
+        Ok(RecoveryCodesModel { recovery_codes })
+    }
+
+    async fn ensure_not_throttled(&self, throttle_keys: &[String]) -> DomainResult<()> {
+        ensure_not_throttled(
+            self.login_throttle_repository.as_ref(),
+            &self.login_throttle_policy,
+            throttle_keys,
+        )
+        .await
+    }
+
+    async fn record_failure(
+        &self,
+        throttle_keys: &[String],
+        ip_address: Option<String>,
+    ) -> DomainError {
+        record_failure(
+            self.login_throttle_repository.as_ref(),
+            &self.login_throttle_policy,
+            throttle_keys,
+            ip_address,
+            INVALID_VERIFICATION_CODE,
+        )
+        .await
+    }
+}
+
+/// Accepts either a TOTP code or an unused recovery code. Both are single use:
+/// an accepted TOTP step cannot be replayed and a recovery code is burnt.
+pub async fn verify_second_factor<R>(
+    two_factor_repository: &R,
+    brawler_entity: &BrawlerEntity,
+    code: &str,
+) -> DomainResult<bool>
+where
+    R: TwoFactorRepository + Send + Sync,
+{
+    let Some(secret) = brawler_entity.totp_secret.as_ref() else {
+        return Ok(false);
+    };
+
+    if totp::is_totp_code(code) {
+        return match totp::verify(secret, code)? {
+            Some(totp_step) => Ok(two_factor_repository
+                .use_totp_step(brawler_entity.id, totp_step)
+                .await?),
+            None => Ok(false),
+        };
+    }
+
+    let code = totp::normalize_recovery_code(code);
+    let recovery_codes = two_factor_repository
+        .list_unused_recovery_codes(brawler_entity.id)
+        .await?;
+
+    for recovery_code in recovery_codes {
+        if argon2::verify(code.clone(), recovery_code.code_hash)? {
+            return Ok(two_factor_repository
+                .use_recovery_code(recovery_code.id)
+                .await?);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns the codes to show once alongside the hashes to store.
+fn generate_recovery_codes() -> DomainResult<(Vec<String>, Vec<String>)> {
+    let recovery_codes = totp::generate_recovery_codes();
+    let recovery_code_hashes = recovery_codes
+        .iter()
+        .map(|code| argon2::hash(totp::normalize_recovery_code(code)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok((recovery_codes, recovery_code_hashes))
+}
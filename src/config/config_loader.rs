@@ -1,6 +1,9 @@
 use anyhow::Result;
 
-use crate::config::{config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server}, stage::Stage};
+use std::{net::IpAddr, str::FromStr};
+
+use crate::config::{config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, JwtKeyEnv, JwtKeyStatus, MailTransport, MailerEnv, PasswordResetEnv, Scheduler, Server, TotpEnv}, stage::Stage};
+use crate::domain::value_objects::{crew_bounds::CrewBounds, login_throttle_policy::LoginThrottlePolicy, mission_retention::MissionRetention};
 
 pub fn load() -> Result<DotEnvyConfig> {
     dotenvy::dotenv().ok();
@@ -22,12 +25,53 @@ pub fn load() -> Result<DotEnvyConfig> {
         url: std::env::var("DATABASE_URL").expect("DATABASE_URL not set"),
     };
 
-    let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET not set");
+    let crew_bounds = CrewBounds {
+        min_crew: std::env::var("MIN_CREW_PER_MISSION")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse()?,
+        max_crew: std::env::var("MAX_CREW_PER_MISSION")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()?,
+    };
+    if crew_bounds.min_crew < 1 || crew_bounds.min_crew > crew_bounds.max_crew {
+        anyhow::bail!("MIN_CREW_PER_MISSION must be between 1 and MAX_CREW_PER_MISSION");
+    }
+
+    let mission_retention = MissionRetention {
+        restore_window_hours: std::env::var("MISSION_RESTORE_WINDOW_HOURS")
+            .unwrap_or_else(|_| "72".to_string())
+            .parse()?,
+        purge_after_days: std::env::var("MISSION_PURGE_AFTER_DAYS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()?,
+        purge_interval_minutes: std::env::var("MISSION_PURGE_INTERVAL_MINUTES")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()?,
+    };
+    if mission_retention.restore_window_hours < 0
+        || mission_retention.restore_window_hours > mission_retention.purge_after_days * 24
+    {
+        anyhow::bail!("MISSION_RESTORE_WINDOW_HOURS must be between 0 and MISSION_PURGE_AFTER_DAYS");
+    }
+    if mission_retention.purge_interval_minutes == 0 {
+        anyhow::bail!("MISSION_PURGE_INTERVAL_MINUTES must be at least 1");
+    }
+
+    let scheduler = Scheduler {
+        mission_expiry_interval_seconds: std::env::var("MISSION_EXPIRY_INTERVAL_SECONDS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()?,
+    };
+    if scheduler.mission_expiry_interval_seconds == 0 {
+        anyhow::bail!("MISSION_EXPIRY_INTERVAL_SECONDS must be at least 1");
+    }
 
     let config = DotEnvyConfig {
         server,
         database,
-        secret,
+        crew_bounds,
+        mission_retention,
+        scheduler,
     };
 
     Ok(config)
@@ -40,20 +84,104 @@ pub fn get_stage() ->Stage {
 
     Stage::from_str(&stage_str).unwrap_or_default()
 }
-pub fn get_user_secret() -> Result<String>{
-    let secret_env = std::env::var("JWT_SECRET")
-    .map_err(|_| anyhow::anyhow!("JWT_SECRET not set"))?;
-    Ok(secret_env)
-}
-
-
-// pub fn get_user_secret_env() -> Result<JwtEnv> {
 pub fn get_jwt_env() -> Result<JwtEnv> {
     dotenvy::dotenv().ok();
 
     Ok(JwtEnv {
-        secret: std::env::var("JWT_USER_SECRET")?,
         lift_time_days: std::env::var("JTW_LIFTTIME_DAYS")?.parse::<i64>()?,
+        access_lift_time_minutes: std::env::var("JWT_ACCESS_LIFETIME_MINUTES")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse::<i64>()?,
+        mfa_lift_time_minutes: std::env::var("JWT_MFA_LIFETIME_MINUTES")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse::<i64>()?,
+    })
+}
+
+pub fn get_totp_env() -> TotpEnv {
+    dotenvy::dotenv().ok();
+
+    TotpEnv {
+        issuer: std::env::var("TOTP_ISSUER").unwrap_or_else(|_| "Brawlers".to_string()),
+    }
+}
+
+pub fn get_login_throttle_policy() -> Result<LoginThrottlePolicy> {
+    dotenvy::dotenv().ok();
+
+    Ok(LoginThrottlePolicy {
+        max_failures: std::env::var("LOGIN_MAX_FAILURES")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()?,
+        base_delay_seconds: std::env::var("LOGIN_BACKOFF_BASE_SECONDS")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse()?,
+        lockout_minutes: std::env::var("LOGIN_LOCKOUT_MINUTES")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse()?,
+    })
+}
+
+/// Signing keys come from the JSON file at `JWT_KEYS_FILE`. Without it the
+/// server falls back to a single HS256 key built from `JWT_SECRET`.
+pub fn get_jwt_keys() -> Result<Vec<JwtKeyEnv>> {
+    dotenvy::dotenv().ok();
+
+    if let Ok(path) = std::env::var("JWT_KEYS_FILE") {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path, e))?;
+        let keys: Vec<JwtKeyEnv> = serde_json::from_str(&content)?;
+        return Ok(keys);
+    }
+
+    let secret = std::env::var("JWT_SECRET")
+        .map_err(|_| anyhow::anyhow!("JWT_SECRET not set"))?;
+
+    Ok(vec![JwtKeyEnv {
+        kid: "default".to_string(),
+        algorithm: "HS256".to_string(),
+        status: JwtKeyStatus::Active,
+        secret: Some(secret),
+        private_key_path: None,
+        public_key_path: None,
+    }])
+}
+
+pub fn get_password_reset_env() -> Result<PasswordResetEnv> {
+    dotenvy::dotenv().ok();
+
+    Ok(PasswordResetEnv {
+        url: std::env::var("PASSWORD_RESET_URL")
+            .unwrap_or_else(|_| "http://localhost:4200/reset-password".to_string()),
+        lift_time_minutes: std::env::var("PASSWORD_RESET_LIFETIME_MINUTES")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<i64>()?,
+    })
+}
+
+/// `MAIL_TRANSPORT` picks `smtp`, `file` or `memory`. Only the local stage
+/// falls back to memory when it is unset, so local runs never need a mail
+/// server while other stages cannot silently drop mail.
+pub fn get_mailer_env() -> Result<MailerEnv> {
+    dotenvy::dotenv().ok();
+
+    let transport = match std::env::var("MAIL_TRANSPORT") {
+        Ok(transport) => MailTransport::from_str(&transport)?,
+        Err(_) if get_stage() == Stage::Local => MailTransport::Memory,
+        Err(_) => anyhow::bail!("MAIL_TRANSPORT must be set outside the local stage"),
+    };
+
+    Ok(MailerEnv {
+        transport,
+        from: std::env::var("MAIL_FROM")
+            .unwrap_or_else(|_| "Brawlers <no-reply@localhost>".to_string()),
+        smtp_host: std::env::var("SMTP_HOST").ok(),
+        smtp_port: std::env::var("SMTP_PORT")
+            .unwrap_or_else(|_| "587".to_string())
+            .parse()?,
+        smtp_username: std::env::var("SMTP_USERNAME").ok(),
+        smtp_password: std::env::var("SMTP_PASSWORD").ok(),
+        file_dir: std::env::var("MAIL_FILE_DIR").unwrap_or_else(|_| "mails".to_string()),
     })
 }
 
@@ -69,4 +197,23 @@ pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
         api_key,
         api_secret,
     })
-}
\ No newline at end of file
+}
+
+/// `TRUSTED_PROXIES` lists, comma-separated, the proxy IPs whose
+/// `X-Forwarded-For` header is believed. Unset means none are.
+pub fn get_trusted_proxies() -> Result<Vec<IpAddr>> {
+    dotenvy::dotenv().ok();
+
+    let trusted_proxies = std::env::var("TRUSTED_PROXIES").unwrap_or_default();
+
+    trusted_proxies
+        .split(',')
+        .map(str::trim)
+        .filter(|proxy| !proxy.is_empty())
+        .map(|proxy| {
+            proxy
+                .parse::<IpAddr>()
+                .map_err(|_| anyhow::anyhow!("Invalid address in TRUSTED_PROXIES: {}", proxy))
+        })
+        .collect()
+}
@@ -1,3 +1,9 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::domain::value_objects::{crew_bounds::CrewBounds, mission_retention::MissionRetention};
+
 #[derive(Debug, Clone)]
 pub struct Server {
     pub port: u16,
@@ -10,19 +16,91 @@ pub struct Database {
     pub url: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    /// How often in-progress missions past their deadline are failed.
+    pub mission_expiry_interval_seconds: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
-    pub secret: String,
-    // pub max_crew_per_mission: u32,
+    pub crew_bounds: CrewBounds,
+    pub mission_retention: MissionRetention,
+    pub scheduler: Scheduler,
 }
 
 #[derive(Debug, Clone)]
 pub struct JwtEnv {
-    //pub struct UserSecret {
-    pub secret: String,
     pub lift_time_days: i64,
+    pub access_lift_time_minutes: i64,
+    pub mfa_lift_time_minutes: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TotpEnv {
+    /// Shown as the account label in authenticator apps.
+    pub issuer: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JwtKeyStatus {
+    /// Signs new tokens; exactly one key may be active.
+    Active,
+    /// No longer signs but still verifies tokens issued before a rotation.
+    Verifying,
+    Retired,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JwtKeyEnv {
+    pub kid: String,
+    /// `HS256`, `RS256` or `EdDSA`.
+    pub algorithm: String,
+    pub status: JwtKeyStatus,
+    pub secret: Option<String>,
+    pub private_key_path: Option<String>,
+    pub public_key_path: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetEnv {
+    /// Page that receives the token as `?token=`.
+    pub url: String,
+    pub lift_time_minutes: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MailTransport {
+    Smtp,
+    File,
+    Memory,
+}
+
+impl FromStr for MailTransport {
+    type Err = anyhow::Error;
+
+    fn from_str(transport: &str) -> Result<Self, Self::Err> {
+        match transport {
+            "smtp" => Ok(Self::Smtp),
+            "file" => Ok(Self::File),
+            "memory" => Ok(Self::Memory),
+            _ => Err(anyhow::anyhow!("Invalid mail transport")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MailerEnv {
+    pub transport: MailTransport,
+    pub from: String,
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub file_dir: String,
 }
 
 #[derive(Debug, Clone)]
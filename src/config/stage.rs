@@ -1,5 +1,4 @@
-use std::fmt;
-use anyhow::Result;
+use std::{fmt, str::FromStr};
 
 #[derive(Debug, Clone, Default, PartialEq)]
 
@@ -21,8 +20,10 @@ impl fmt::Display for Stage {
     }
 }
 
-impl Stage {
-    pub fn from_str(stage: &str) ->Result<Self> {
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(stage: &str) -> Result<Self, Self::Err> {
         match stage {
             "local" => Ok(Self::Local),
             "development" => Ok(Self::Development),
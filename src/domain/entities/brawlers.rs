@@ -1,4 +1,10 @@
-use crate::infrastructure::database::schema::brawlers;
+use crate::{
+    domain::value_objects::{
+        brawler_model::{MissionStatsModel, PrivateProfileModel, PublicProfileModel},
+        brawler_roles::BrawlerRoles,
+    },
+    infrastructure::database::schema::brawlers,
+};
 use chrono::NaiveDateTime;
 use diesel::{Selectable, prelude::*};
 
@@ -13,7 +19,37 @@ pub struct BrawlerEntity {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
+    pub role: BrawlerRoles,
+    pub suspended_at: Option<NaiveDateTime>,
+    pub email: Option<String>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled_at: Option<NaiveDateTime>,
+    pub totp_last_used_step: Option<i64>,
+    pub bio: Option<String>,
+}
+
+impl BrawlerEntity {
+    pub fn to_public_profile(&self, mission_stats: MissionStatsModel) -> PublicProfileModel {
+        PublicProfileModel {
+            id: self.id,
+            username: self.username.clone(),
+            display_name: self.display_name.clone(),
+            avatar_url: self.avatar_url.clone(),
+            bio: self.bio.clone(),
+            role: self.role,
+            created_at: self.created_at,
+            mission_stats,
+        }
+    }
 
+    pub fn to_private_profile(&self, mission_stats: MissionStatsModel) -> PrivateProfileModel {
+        PrivateProfileModel {
+            profile: self.to_public_profile(mission_stats),
+            email: self.email.clone(),
+            two_factor_enabled: self.totp_enabled_at.is_some(),
+            updated_at: self.updated_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -22,4 +58,13 @@ pub struct RegisterBrawlerEntity {
     pub username: String,
     pub password: String,
     pub display_name: String,
-}
\ No newline at end of file
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = brawlers)]
+pub struct EditProfileEntity {
+    pub display_name: Option<String>,
+    /// `Some(None)` clears the bio.
+    pub bio: Option<Option<String>>,
+}
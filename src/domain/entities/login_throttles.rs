@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{lockout_audits, login_throttles};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Insertable, AsChangeset)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = login_throttles)]
+#[diesel(primary_key(throttle_key))]
+#[diesel(treat_none_as_null = true)]
+pub struct LoginThrottleEntity {
+    pub throttle_key: String,
+    pub failed_count: i32,
+    pub last_failed_at: NaiveDateTime,
+    pub locked_until: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = lockout_audits)]
+pub struct AddLockoutAuditEntity {
+    pub throttle_key: String,
+    pub failed_count: i32,
+    pub locked_until: NaiveDateTime,
+    pub ip_address: Option<String>,
+}
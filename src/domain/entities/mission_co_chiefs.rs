@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{mission_model::CoChiefModel, mission_permissions::MissionPermissions},
+    infrastructure::database::schema::mission_co_chiefs,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_co_chiefs)]
+pub struct MissionCoChiefEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub can_start: bool,
+    pub can_complete: bool,
+    pub can_fail: bool,
+    pub granted_at: NaiveDateTime,
+}
+
+impl MissionCoChiefEntity {
+    pub fn allows(&self, permission: MissionPermissions) -> bool {
+        match permission {
+            MissionPermissions::Start => self.can_start,
+            MissionPermissions::Complete => self.can_complete,
+            MissionPermissions::Fail => self.can_fail,
+        }
+    }
+
+    pub fn to_model(&self) -> CoChiefModel {
+        CoChiefModel {
+            brawler_id: self.brawler_id,
+            can_start: self.can_start,
+            can_complete: self.can_complete,
+            can_fail: self.can_fail,
+            granted_at: self.granted_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, AsChangeset)]
+#[diesel(table_name = mission_co_chiefs)]
+pub struct AddMissionCoChiefEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub can_start: bool,
+    pub can_complete: bool,
+    pub can_fail: bool,
+}
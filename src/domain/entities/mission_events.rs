@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde_json::Value;
+
+use crate::{
+    domain::value_objects::{
+        mission_event_kinds::MissionEventKinds, mission_model::MissionEventModel,
+    },
+    infrastructure::database::schema::mission_events,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_events)]
+pub struct MissionEventEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub actor_id: Option<i32>,
+    pub kind: MissionEventKinds,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionEventEntity {
+    pub fn to_model(&self) -> MissionEventModel {
+        MissionEventModel {
+            id: self.id,
+            actor_id: self.actor_id,
+            kind: self.kind,
+            before: self.before.clone(),
+            after: self.after.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_events)]
+pub struct AddMissionEventEntity {
+    pub mission_id: i32,
+    /// `None` for changes the server makes on its own.
+    pub actor_id: Option<i32>,
+    pub kind: MissionEventKinds,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
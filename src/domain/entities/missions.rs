@@ -2,7 +2,15 @@ use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions,
+    domain::{
+        entities::brawlers::BrawlerEntity,
+        value_objects::{
+            mission_model::{MissionModel, TrashedMissionModel},
+            mission_retention::MissionRetention,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::schema::missions,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -12,41 +20,126 @@ pub struct MissionEntity {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub starts_at: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
 }
 
 impl MissionEntity {
+    /// The chief-editable state recorded in mission events.
+    pub fn snapshot(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name,
+            "description": self.description,
+            "status": self.status,
+            "chief_id": self.chief_id,
+            "min_crew": self.min_crew,
+            "max_crew": self.max_crew,
+            "starts_at": self.starts_at,
+            "deadline": self.deadline,
+        })
+    }
+
     pub fn to_model(&self, crew_count: i64) -> MissionModel {
         MissionModel {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
+            status: self.status,
             chief_id: self.chief_id,
             crew_count,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            starts_at: self.starts_at,
+            deadline: self.deadline,
             created_at: self.created_at,
             updated_at: self.updated_at,
+            search_rank: None,
+            search_snippet: None,
+        }
+    }
+
+    /// `None` unless the mission has been removed.
+    pub fn to_trashed_model(&self, retention: &MissionRetention) -> Option<TrashedMissionModel> {
+        let deleted_at = self.deleted_at?;
+
+        Some(TrashedMissionModel {
+            id: self.id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            status: self.status,
+            chief_id: self.chief_id,
+            deleted_at,
+            restorable_until: retention.restorable_until(deleted_at),
+            purged_at: retention.purged_at(deleted_at),
+        })
+    }
+}
+
+/// A listed mission with the values computed alongside it.
+#[derive(Debug, Clone)]
+pub struct MissionListingEntity {
+    pub mission: MissionEntity,
+    pub crew_count: i64,
+    pub search_rank: Option<f32>,
+    pub search_snippet: Option<String>,
+}
+
+impl MissionListingEntity {
+    pub fn to_model(&self) -> MissionModel {
+        MissionModel {
+            search_rank: self.search_rank,
+            search_snippet: self.search_snippet.clone(),
+            ..self.mission.to_model(self.crew_count)
         }
     }
 }
 
+/// A mission as it was when cancelled, with the crew it released.
+#[derive(Debug, Clone)]
+pub struct CancelledMissionEntity {
+    pub mission: MissionEntity,
+    pub released_crew: Vec<BrawlerEntity>,
+}
+
 #[derive(Debug, Clone, Insertable)]
 #[diesel(table_name = missions)]
 pub struct AddMissionEntity {
     pub chief_id: i32,
     pub name: String,
-    pub status: String,
+    pub status: MissionStatuses,
     pub description: Option<String>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub starts_at: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
 #[diesel(table_name = missions)]
 pub struct EditMissionEntity {
-    pub chief_id: i32,
     pub name: Option<String>,
     pub description: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    /// `Some(None)` clears the column.
+    pub starts_at: Option<Option<NaiveDateTime>>,
+    pub deadline: Option<Option<NaiveDateTime>>,
+}
+
+impl EditMissionEntity {
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.min_crew.is_none()
+            && self.max_crew.is_none()
+            && self.starts_at.is_none()
+            && self.deadline.is_none()
+    }
 }
\ No newline at end of file
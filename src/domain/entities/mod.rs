@@ -1,3 +1,10 @@
 pub mod brawlers;
 pub mod crew_memberships;
-pub mod missions;
\ No newline at end of file
+pub mod login_throttles;
+pub mod mission_co_chiefs;
+pub mod mission_events;
+pub mod missions;
+pub mod password_reset_tokens;
+pub mod recovery_codes;
+pub mod refresh_tokens;
+pub mod sessions;
\ No newline at end of file
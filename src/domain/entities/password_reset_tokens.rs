@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::password_reset_tokens;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = password_reset_tokens)]
+pub struct PasswordResetTokenEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = password_reset_tokens)]
+pub struct AddPasswordResetTokenEntity {
+    pub brawler_id: i32,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+}
@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::recovery_codes;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = recovery_codes)]
+pub struct RecoveryCodeEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub code_hash: String,
+    pub used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = recovery_codes)]
+pub struct AddRecoveryCodeEntity {
+    pub brawler_id: i32,
+    pub code_hash: String,
+}
@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::refresh_tokens;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = refresh_tokens)]
+pub struct RefreshTokenEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub token_hash: String,
+    pub family_id: String,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub replaced_by: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = refresh_tokens)]
+pub struct AddRefreshTokenEntity {
+    pub brawler_id: i32,
+    pub token_hash: String,
+    pub family_id: String,
+    pub expires_at: NaiveDateTime,
+}
@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::session_model::SessionModel,
+    infrastructure::database::schema::sessions,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = sessions)]
+pub struct SessionEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub jti: String,
+    pub device_name: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+}
+
+impl SessionEntity {
+    pub fn to_model(&self, current_jti: &str) -> SessionModel {
+        SessionModel {
+            id: self.id,
+            device_name: self.device_name.clone(),
+            user_agent: self.user_agent.clone(),
+            ip_address: self.ip_address.clone(),
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            current: self.jti == current_jti,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = sessions)]
+pub struct AddSessionEntity {
+    pub brawler_id: i32,
+    pub jti: String,
+    pub device_name: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub expires_at: NaiveDateTime,
+}
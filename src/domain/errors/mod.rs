@@ -0,0 +1,125 @@
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use serde::{Deserialize, Serialize};
+
+pub type DomainResult<T> = std::result::Result<T, DomainError>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DomainError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{message}")]
+    Validation {
+        message: String,
+        fields: Vec<FieldError>,
+    },
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error("{message}")]
+    RateLimited {
+        message: String,
+        retry_after_seconds: i64,
+    },
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+impl DomainError {
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(message.into())
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::Conflict(message.into())
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::Validation {
+            message: message.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn invalid_fields(message: impl Into<String>, fields: Vec<FieldError>) -> Self {
+        Self::Validation {
+            message: message.into(),
+            fields,
+        }
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized(message.into())
+    }
+
+    pub fn rate_limited(message: impl Into<String>, retry_after_seconds: i64) -> Self {
+        Self::RateLimited {
+            message: message.into(),
+            retry_after_seconds,
+        }
+    }
+
+    /// Stable, machine readable identifier sent to clients as `code`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomainError::NotFound(_) => "not_found",
+            DomainError::Forbidden(_) => "forbidden",
+            DomainError::Conflict(_) => "conflict",
+            DomainError::Validation { .. } => "validation_failed",
+            DomainError::Unauthorized(_) => "unauthorized",
+            DomainError::RateLimited { .. } => "rate_limited",
+            DomainError::Internal(_) => "internal_error",
+        }
+    }
+}
+
+// Repositories still speak `anyhow`, so known Diesel failures are lifted into
+// their domain meaning here and everything else stays an internal error.
+impl From<anyhow::Error> for DomainError {
+    fn from(error: anyhow::Error) -> Self {
+        // Guards run inside repository transactions and hand their domain
+        // errors back through `anyhow`; those pass through unchanged.
+        let error = match error.downcast::<DomainError>() {
+            Ok(domain_error) => return domain_error,
+            Err(error) => error,
+        };
+
+        match error.downcast_ref::<DieselError>() {
+            Some(DieselError::NotFound) => DomainError::not_found("Resource not found"),
+            Some(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => {
+                DomainError::conflict("Resource already exists")
+            }
+            Some(DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _)) => {
+                DomainError::not_found("Referenced resource not found")
+            }
+            _ => DomainError::Internal(error),
+        }
+    }
+}
+
+impl From<DieselError> for DomainError {
+    fn from(error: DieselError) -> Self {
+        DomainError::from(anyhow::Error::from(error))
+    }
+}
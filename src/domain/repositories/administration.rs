@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::missions::CancelledMissionEntity;
+
+#[async_trait]
+pub trait AdministrationRepository {
+    async fn force_close_mission(
+        &self,
+        mission_id: i32,
+        moderator_id: i32,
+    ) -> Result<CancelledMissionEntity>;
+    async fn restore_mission(&self, mission_id: i32, admin_id: i32) -> Result<i32>;
+    async fn suspend_brawler(&self, brawler_id: i32, suspended_at: NaiveDateTime) -> Result<i32>;
+    async fn unsuspend_brawler(&self, brawler_id: i32) -> Result<i32>;
+}
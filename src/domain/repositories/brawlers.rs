@@ -1,11 +1,32 @@
-use crate::{domain::{entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity}, value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage}}, infrastructure::{cloudinary::{UploadImageOptions}}};
+use crate::{domain::{entities::brawlers::{BrawlerEntity, EditProfileEntity, RegisterBrawlerEntity}, value_objects::{base64_image::Base64Image, brawler_model::MissionStatsModel, uploaded_image::UploadedImage}}, infrastructure::{cloudinary::{UploadImageOptions}}};
 use anyhow::Result;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait BrawlerRepository {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity>;
+    /// Inserts the brawler and uploads the avatar under the new id as one unit:
+    /// a failed upload rolls the insert back and a failed write removes the image.
+    async fn register_with_avatar(
+        &self,
+        register_brawler_entity: RegisterBrawlerEntity,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<(i32, UploadedImage)>;
+    /// Usernames are matched case-insensitively.
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity>;
+    async fn username_exists(&self, username: &str) -> Result<bool>;
+    async fn email_exists(&self, email: &str) -> Result<bool>;
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity>;
+    async fn find_by_email(&self, email: &str) -> Result<BrawlerEntity>;
+    async fn update_profile(
+        &self,
+        brawler_id: i32,
+        edit_profile_entity: EditProfileEntity,
+    ) -> Result<BrawlerEntity>;
+    /// Counts only missions that have not been deleted.
+    async fn mission_stats(&self, brawler_id: i32) -> Result<MissionStatsModel>;
+    async fn update_password(&self, brawler_id: i32, password: String) -> Result<()>;
     async fn upload_avatar(
         &self,
         brawler_id: i32,
@@ -1,10 +1,20 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::domain::entities::crew_memberships::CrewMembershipEntity;
+use crate::domain::{
+    entities::crew_memberships::CrewMembershipEntity,
+    repositories::mission_operation::MissionGuard,
+};
 
 #[async_trait]
 pub trait CrewOperationRepository {
-    async fn join(&self, crew_member_ships: CrewMembershipEntity) -> Result<()>;
-    async fn leave(&self, crew_member_ships: CrewMembershipEntity) -> Result<()>;
-}
\ No newline at end of file
+    /// Inserts the membership only if `guard` passes, in the same transaction
+    /// as the mission row lock, so capacity cannot be overrun.
+    async fn join(&self, crew_member_ships: CrewMembershipEntity, guard: impl MissionGuard)
+    -> Result<()>;
+    async fn leave(
+        &self,
+        crew_member_ships: CrewMembershipEntity,
+        guard: impl MissionGuard,
+    ) -> Result<()>;
+}
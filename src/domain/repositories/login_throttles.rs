@@ -0,0 +1,21 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::login_throttles::LoginThrottleEntity,
+    value_objects::login_throttle_policy::LoginThrottlePolicy,
+};
+
+#[async_trait]
+pub trait LoginThrottleRepository {
+    async fn find(&self, throttle_keys: &[String]) -> Result<Vec<LoginThrottleEntity>>;
+    /// Applies `policy` to the stored counter under a row lock and writes a
+    /// lockout audit record when the failure locks the key.
+    async fn record_failure(
+        &self,
+        throttle_key: &str,
+        policy: &LoginThrottlePolicy,
+        ip_address: Option<String>,
+    ) -> Result<LoginThrottleEntity>;
+    async fn clear(&self, throttle_key: &str) -> Result<()>;
+}
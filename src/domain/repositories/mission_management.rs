@@ -1,11 +1,46 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
-use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
+use crate::domain::{
+    entities::missions::{AddMissionEntity, EditMissionEntity, MissionEntity},
+    repositories::mission_operation::MissionGuard,
+};
 
 #[async_trait]
 pub trait MissionManagementRepository {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
-}
\ No newline at end of file
+    /// Applies the changes once `guard` accepts the locked mission; a missing
+    /// or removed mission is not found.
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_entity: EditMissionEntity,
+        guard: impl MissionGuard,
+    ) -> Result<i32>;
+    /// Moves the mission to the trash under the same rule as `edit`.
+    /// `removed_at` comes from the caller's clock, the one the restore window
+    /// and the purge are measured with.
+    async fn remove(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        removed_at: NaiveDateTime,
+        guard: impl MissionGuard,
+    ) -> Result<()>;
+    /// Removed missions, most recently removed first. Every chief's when
+    /// `chief_id` is `None`.
+    async fn get_trash(&self, chief_id: Option<i32>) -> Result<Vec<MissionEntity>>;
+    /// Brings back a removed mission once `guard` accepts it; a mission that
+    /// is not removed is not found.
+    async fn restore(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        guard: impl MissionGuard,
+    ) -> Result<i32>;
+    /// Deletes missions removed before `removed_before` for good, with their
+    /// crew memberships and events. Returns how many were deleted.
+    async fn purge_removed(&self, removed_before: NaiveDateTime) -> Result<usize>;
+}
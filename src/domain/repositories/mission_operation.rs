@@ -1,9 +1,96 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
+use crate::domain::{
+    entities::{
+        mission_co_chiefs::{AddMissionCoChiefEntity, MissionCoChiefEntity},
+        missions::{CancelledMissionEntity, MissionEntity},
+    },
+    errors::DomainResult,
+};
+
+/// Business rule checked against a mission while its row is locked, given the
+/// current crew count. An `Err` aborts the change and is returned as is.
+pub trait MissionGuard: FnOnce(&MissionEntity, i64) -> DomainResult<()> + Send + 'static {}
+
+impl<F> MissionGuard for F where F: FnOnce(&MissionEntity, i64) -> DomainResult<()> + Send + 'static {}
+
+/// A `MissionGuard` that is also given the acting brawler's co-chief grant on
+/// the mission, if they hold one.
+pub trait LeadershipGuard:
+    FnOnce(&MissionEntity, i64, Option<&MissionCoChiefEntity>) -> DomainResult<()> + Send + 'static
+{
+}
+
+impl<F> LeadershipGuard for F where
+    F: FnOnce(&MissionEntity, i64, Option<&MissionCoChiefEntity>) -> DomainResult<()>
+        + Send
+        + 'static
+{
+}
+
+/// Each method records the change as a mission event by `actor_id`, which is
+/// `None` for changes the server makes on its own. Status changes also reject
+/// a move the status transition table does not allow.
 #[async_trait]
 pub trait MissionOperationRepository {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-}
\ No newline at end of file
+    async fn to_progress(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32>;
+    async fn to_completed(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32>;
+    async fn to_failed(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32>;
+    /// In-progress missions whose deadline passed before `now`, oldest
+    /// deadline first.
+    async fn overdue_missions(&self, now: NaiveDateTime) -> Result<Vec<i32>>;
+    /// Also releases the whole crew, returning who was in it.
+    async fn to_cancelled(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<CancelledMissionEntity>;
+    async fn to_archived(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32>;
+    /// Moves `new_chief_id` from the crew to the chief's seat, optionally
+    /// putting the old chief in the crew. Fails if `new_chief_id` is not crew.
+    async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        new_chief_id: i32,
+        old_chief_stays: bool,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32>;
+    /// Grants or updates a co-chief. Fails if the brawler is not crew.
+    async fn grant_co_chief(
+        &self,
+        actor_id: i32,
+        co_chief: AddMissionCoChiefEntity,
+        guard: impl LeadershipGuard,
+    ) -> Result<MissionCoChiefEntity>;
+    async fn revoke_co_chief(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        brawler_id: i32,
+        guard: impl LeadershipGuard,
+    ) -> Result<()>;
+}
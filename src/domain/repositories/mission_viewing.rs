@@ -2,13 +2,42 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::domain::{
-    entities::missions::MissionEntity, value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter},
+    entities::{
+        mission_co_chiefs::MissionCoChiefEntity,
+        mission_events::MissionEventEntity,
+        missions::{MissionEntity, MissionListingEntity},
+    },
+    value_objects::{
+        brawler_model::CrewMemberModel,
+        mission_filter::{MissionFilter, MissionPageRequest, MissionViewer},
+    },
 };
 
 #[async_trait]
 pub trait MissionViewingRepository {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64>;
     async fn get_one(&self, mission_id: i32) -> Result<MissionEntity>;
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionEntity>>;
-    async fn get_mission_count(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
-}
\ No newline at end of file
+    /// Returns up to `page.limit + 1` missions after `page.cursor`, each with
+    /// its crew count and search match; the extra row only signals that
+    /// another page exists.
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer: &MissionViewer,
+        page: &MissionPageRequest,
+    ) -> Result<Vec<MissionListingEntity>>;
+    /// Counts every mission matching the filter and search, ignoring the
+    /// cursor and limit.
+    async fn count_all(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer: &MissionViewer,
+        page: &MissionPageRequest,
+    ) -> Result<i64>;
+    /// Members ordered by when they joined.
+    async fn get_crew(&self, mission_id: i32) -> Result<Vec<CrewMemberModel>>;
+    /// Oldest event first.
+    async fn get_history(&self, mission_id: i32) -> Result<Vec<MissionEventEntity>>;
+    /// Oldest grant first.
+    async fn get_co_chiefs(&self, mission_id: i32) -> Result<Vec<MissionCoChiefEntity>>;
+}
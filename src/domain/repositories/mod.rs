@@ -1,6 +1,12 @@
+pub mod administration;
 pub mod brawlers;
 pub mod crew_operation;
+pub mod login_throttles;
 pub mod mission_management;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod password_resets;
+pub mod refresh_tokens;
+pub mod sessions;
+pub mod two_factor;
 // pub mod transaction_provider;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::password_reset_tokens::AddPasswordResetTokenEntity;
+
+#[async_trait]
+pub trait PasswordResetRepository {
+    /// Stores a new reset token and invalidates any the brawler still holds.
+    async fn add(&self, add_password_reset_token_entity: AddPasswordResetTokenEntity) -> Result<i32>;
+    /// Marks the token used and stores `password` in one transaction. Returns
+    /// `None` when the token is unknown, expired or already used.
+    async fn consume(&self, token_hash: &str, password: String) -> Result<Option<i32>>;
+}
@@ -0,0 +1,16 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity};
+
+#[async_trait]
+pub trait RefreshTokenRepository {
+    async fn find_by_hash(&self, token_hash: &str) -> Result<RefreshTokenEntity>;
+    /// Revokes `refresh_token_id` and stores its successor in one transaction.
+    /// Returns `None` when the token was already revoked by a concurrent rotation.
+    async fn rotate(
+        &self,
+        refresh_token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<Option<i32>>;
+}
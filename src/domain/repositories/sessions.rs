@@ -0,0 +1,24 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::{
+    refresh_tokens::AddRefreshTokenEntity,
+    sessions::{AddSessionEntity, SessionEntity},
+};
+
+#[async_trait]
+pub trait SessionRepository {
+    /// Records the session and its first refresh token together.
+    async fn start(
+        &self,
+        add_session_entity: AddSessionEntity,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<i32>;
+    async fn list_active(&self, brawler_id: i32) -> Result<Vec<SessionEntity>>;
+    async fn is_revoked(&self, jti: &str) -> Result<bool>;
+    /// Revokes the session and every refresh token issued under it.
+    async fn revoke_by_jti(&self, jti: &str) -> Result<()>;
+    async fn revoke(&self, brawler_id: i32, session_id: i32) -> Result<()>;
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()>;
+    async fn revoke_others(&self, brawler_id: i32, current_jti: &str) -> Result<()>;
+}
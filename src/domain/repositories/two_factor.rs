@@ -0,0 +1,29 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::recovery_codes::RecoveryCodeEntity;
+
+#[async_trait]
+pub trait TwoFactorRepository {
+    /// Stores a secret that only takes effect once `enable` confirms it.
+    async fn set_pending_secret(&self, brawler_id: i32, totp_secret: String) -> Result<()>;
+    /// Turns two-factor on and replaces the recovery codes in one transaction.
+    async fn enable(
+        &self,
+        brawler_id: i32,
+        totp_step: i64,
+        recovery_code_hashes: Vec<String>,
+    ) -> Result<()>;
+    async fn disable(&self, brawler_id: i32) -> Result<()>;
+    async fn replace_recovery_codes(
+        &self,
+        brawler_id: i32,
+        recovery_code_hashes: Vec<String>,
+    ) -> Result<()>;
+    async fn list_unused_recovery_codes(&self, brawler_id: i32) -> Result<Vec<RecoveryCodeEntity>>;
+    /// Returns `false` when the code was already used by a concurrent request.
+    async fn use_recovery_code(&self, recovery_code_id: i32) -> Result<bool>;
+    /// Records `totp_step` as used; returns `false` if it, or a later step, was
+    /// already accepted, which stops a code from being replayed.
+    async fn use_totp_step(&self, brawler_id: i32, totp_step: i64) -> Result<bool>;
+}
@@ -1,25 +1,26 @@
-use anyhow::Result;
 use base64::{Engine, engine::general_purpose};
 
+use crate::domain::errors::{DomainError, DomainResult};
+
 #[derive(Debug, Clone)]
 pub struct Base64Image(String); // tuple struct
 
 impl Base64Image {
-    pub fn new(data: String) -> Result<Self> {
+    pub fn new(data: String) -> DomainResult<Self> {
         if data.is_empty() {
-            return Err(anyhow::anyhow!("Base64 image data cannot be empty !!"));
+            return Err(DomainError::validation("Base64 image data cannot be empty !!"));
         }
 
         let bytes = match general_purpose::STANDARD.decode(&data) {
             Ok(bs) => bs,
-            Err(_) => return Err(anyhow::anyhow!("Invalid base64 image data.")),
+            Err(_) => return Err(DomainError::validation("Invalid base64 image data.")),
         };
 
         let file_type = match infer::get(&bytes) {
             Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
                 t.mime_type()
             }
-            _ => return Err(anyhow::anyhow!("Unsupported or invalid base64 image type.")),
+            _ => return Err(DomainError::validation("Unsupported or invalid base64 image type.")),
         };
 
         Ok(Self(format!("data:{};base64,{}", file_type, data)))
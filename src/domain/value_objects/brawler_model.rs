@@ -1,7 +1,12 @@
-use diesel::{prelude::QueryableByName, sql_types::{BigInt, Varchar}};
+use chrono::NaiveDateTime;
+use diesel::{prelude::QueryableByName, sql_types::BigInt};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::{EditProfileEntity, RegisterBrawlerEntity},
+    errors::{DomainError, DomainResult, FieldError},
+    value_objects::{brawler_roles::BrawlerRoles, password_policy, registration_policy},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
@@ -9,27 +14,148 @@ pub struct RegisterBrawlerModel {
     pub password: String,
     pub display_name: String,
     pub upload_avatar: Option<String>,
-    
+    #[serde(default)]
+    pub email: Option<String>,
 }
 
 impl RegisterBrawlerModel {
+    /// Checks every field and returns the model with normalized values, or all
+    /// field errors at once.
+    pub fn validate(self) -> DomainResult<Self> {
+        let mut fields = Vec::new();
+
+        let username = registration_policy::validate_username(&self.username)
+            .unwrap_or_else(|e| {
+                fields.push(e);
+                self.username.clone()
+            });
+        let display_name = registration_policy::normalize_display_name(&self.display_name)
+            .unwrap_or_else(|e| {
+                fields.push(e);
+                self.display_name.clone()
+            });
+        let email = match self.email.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(email) => registration_policy::normalize_email(email)
+                .map_err(|e| fields.push(e))
+                .ok(),
+        };
+        if let Err(e) = password_policy::validate_password("password", &self.password, &username) {
+            fields.push(e);
+        }
+
+        if !fields.is_empty() {
+            return Err(DomainError::invalid_fields("Registration is invalid", fields));
+        }
+
+        Ok(Self {
+            username,
+            display_name,
+            email,
+            ..self
+        })
+    }
+
     pub fn to_entity(&self) -> RegisterBrawlerEntity {
         RegisterBrawlerEntity {
             username: self.username.clone(),
             password: self.password.clone(),
             display_name: self.display_name.clone(),
+            email: self
+                .email
+                .as_ref()
+                .map(|email| email.trim().to_lowercase()),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize,Deserialize, QueryableByName)]
-pub struct BrawlerModel {
-    #[diesel(sql_type = Varchar)]
+/// One row of a mission's crew roster. The counts cover the brawler's whole
+/// history, not just this mission.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrewMemberModel {
+    pub brawler_id: i32,
     pub display_name: String,
-    #[diesel(sql_type = Varchar)]
-    pub avatar_url: String,
-    #[diesel(sql_type = BigInt)]
+    pub avatar_url: Option<String>,
+    pub joined_at: NaiveDateTime,
     pub mission_success_count: i64,
-    #[diesel(sql_type = BigInt)]
     pub mission_joined_count: i64,
-}
\ No newline at end of file
+}
+
+pub const MAX_BIO_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, QueryableByName)]
+pub struct MissionStatsModel {
+    #[diesel(sql_type = BigInt)]
+    pub chiefed_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub joined_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub completed_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub failed_count: i64,
+}
+
+/// What any visitor may see about a brawler.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicProfileModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    pub role: BrawlerRoles,
+    pub created_at: NaiveDateTime,
+    pub mission_stats: MissionStatsModel,
+}
+
+/// The public profile plus fields only the owner may see.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateProfileModel {
+    #[serde(flatten)]
+    pub profile: PublicProfileModel,
+    pub email: Option<String>,
+    pub two_factor_enabled: bool,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditProfileModel {
+    pub display_name: Option<String>,
+    /// An empty string clears the bio.
+    pub bio: Option<String>,
+}
+
+impl EditProfileModel {
+    pub fn to_entity(&self) -> DomainResult<EditProfileEntity> {
+        let mut fields = Vec::new();
+
+        let display_name = match self.display_name.as_deref() {
+            Some(display_name) => registration_policy::normalize_display_name(display_name)
+                .map_err(|e| fields.push(e))
+                .ok(),
+            None => None,
+        };
+
+        let bio = self.bio.as_deref().map(str::trim).map(|bio| {
+            if bio.is_empty() {
+                None
+            } else {
+                Some(bio.to_string())
+            }
+        });
+        if let Some(Some(bio)) = &bio
+            && bio.chars().count() > MAX_BIO_LENGTH
+        {
+            fields.push(FieldError::new(
+                "bio",
+                format!("Bio must be at most {} characters long", MAX_BIO_LENGTH),
+            ));
+        }
+
+        if !fields.is_empty() {
+            return Err(DomainError::invalid_fields("Profile is invalid", fields));
+        }
+
+        Ok(EditProfileEntity { display_name, bio })
+    }
+}
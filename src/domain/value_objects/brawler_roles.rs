@@ -0,0 +1,84 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use serde::{Deserialize, Serialize};
+
+/// Stored as text in `brawlers.role`, which a check constraint limits to
+/// these names.
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Text)]
+pub enum BrawlerRoles {
+    #[default]
+    Brawler,
+    /// May force-close missions.
+    Moderator,
+    Admin,
+}
+
+impl BrawlerRoles {
+    pub const ALL: [BrawlerRoles; 3] = [
+        BrawlerRoles::Brawler,
+        BrawlerRoles::Moderator,
+        BrawlerRoles::Admin,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BrawlerRoles::Brawler => "Brawler",
+            BrawlerRoles::Moderator => "Moderator",
+            BrawlerRoles::Admin => "Admin",
+        }
+    }
+}
+
+impl Display for BrawlerRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for BrawlerRoles {
+    type Err = anyhow::Error;
+
+    fn from_str(role: &str) -> Result<Self, Self::Err> {
+        BrawlerRoles::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == role)
+            .ok_or_else(|| anyhow::anyhow!("Invalid brawler role: {}", role))
+    }
+}
+
+impl ToSql<Text, Pg> for BrawlerRoles {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Pg> for BrawlerRoles {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let role = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        Ok(role.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BrawlerRoles;
+
+    #[test]
+    fn role_names_round_trip() {
+        for role in BrawlerRoles::ALL {
+            assert_eq!(role.as_str().parse::<BrawlerRoles>().unwrap(), role);
+        }
+        assert!("Chief".parse::<BrawlerRoles>().is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::domain::errors::FieldError;
+
+/// Server-wide limits on the crew size a chief may set for a mission.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrewBounds {
+    /// Smallest `min_crew` allowed, and the default for new missions.
+    pub min_crew: i32,
+    /// Largest `max_crew` allowed, and the default for new missions.
+    pub max_crew: i32,
+}
+
+impl CrewBounds {
+    pub fn check(&self, min_crew: i32, max_crew: i32) -> Vec<FieldError> {
+        let mut fields = Vec::new();
+
+        if min_crew < self.min_crew {
+            fields.push(FieldError::new(
+                "min_crew",
+                format!("min_crew must be at least {}", self.min_crew),
+            ));
+        }
+        if max_crew > self.max_crew {
+            fields.push(FieldError::new(
+                "max_crew",
+                format!("max_crew must be at most {}", self.max_crew),
+            ));
+        }
+        if min_crew > max_crew {
+            fields.push(FieldError::new(
+                "min_crew",
+                "min_crew must not be greater than max_crew",
+            ));
+        }
+
+        fields
+    }
+}
@@ -0,0 +1,219 @@
+use chrono::{Duration, NaiveDateTime};
+use sha2::{Digest, Sha256};
+
+use crate::domain::entities::login_throttles::LoginThrottleEntity;
+
+const MAX_BACKOFF_SECONDS: i64 = 15 * 60;
+/// Matches `brawlers.username`, so every real account keeps a readable key.
+const MAX_USERNAME_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginThrottlePolicy {
+    pub max_failures: i32,
+    pub base_delay_seconds: i64,
+    pub lockout_minutes: i64,
+}
+
+impl LoginThrottlePolicy {
+    pub fn username_key(username: &str) -> String {
+        let username = username.trim().to_lowercase();
+
+        // No account has a longer name, but the attempt still has to count
+        // against a key that fits the column.
+        if username.chars().count() > MAX_USERNAME_LENGTH {
+            return format!("username-digest:{:x}", Sha256::digest(username.as_bytes()));
+        }
+
+        format!("username:{}", username)
+    }
+
+    pub fn ip_key(ip_address: &str) -> String {
+        format!("ip:{}", ip_address)
+    }
+
+    pub fn mfa_key(brawler_id: i32) -> String {
+        format!("mfa:{}", brawler_id)
+    }
+
+    /// Seconds the caller must wait before another attempt, if any.
+    pub fn retry_after(&self, throttle: &LoginThrottleEntity, now: NaiveDateTime) -> Option<i64> {
+        if let Some(locked_until) = throttle.locked_until {
+            if locked_until > now {
+                return Some((locked_until - now).num_seconds().max(1));
+            }
+            return None;
+        }
+
+        if throttle.failed_count == 0 || self.is_stale(throttle, now) {
+            return None;
+        }
+
+        let allowed_at = throttle.last_failed_at + self.backoff(throttle.failed_count);
+        (allowed_at > now).then(|| (allowed_at - now).num_seconds().max(1))
+    }
+
+    /// Applies one more failure to `current` and reports whether it triggered a lockout.
+    pub fn register_failure(
+        &self,
+        throttle_key: &str,
+        current: Option<&LoginThrottleEntity>,
+        now: NaiveDateTime,
+    ) -> (LoginThrottleEntity, bool) {
+        if let Some(throttle) = current.filter(|t| t.locked_until.is_some_and(|until| until > now)) {
+            let throttle = LoginThrottleEntity {
+                failed_count: throttle.failed_count + 1,
+                last_failed_at: now,
+                ..throttle.clone()
+            };
+            return (throttle, false);
+        }
+
+        // A finished lockout or a long quiet period starts the count over.
+        let previous_count = match current {
+            Some(throttle)
+                if throttle.locked_until.is_none() && !self.is_stale(throttle, now) =>
+            {
+                throttle.failed_count
+            }
+            _ => 0,
+        };
+
+        let failed_count = previous_count + 1;
+        let locked = failed_count >= self.max_failures;
+
+        let throttle = LoginThrottleEntity {
+            throttle_key: throttle_key.to_string(),
+            failed_count,
+            last_failed_at: now,
+            locked_until: locked.then(|| now + Duration::minutes(self.lockout_minutes)),
+        };
+
+        (throttle, locked)
+    }
+
+    fn backoff(&self, failed_count: i32) -> Duration {
+        let exponent = (failed_count - 1).clamp(0, 20) as u32;
+        let seconds = self
+            .base_delay_seconds
+            .saturating_mul(2_i64.saturating_pow(exponent))
+            .min(MAX_BACKOFF_SECONDS);
+        Duration::seconds(seconds)
+    }
+
+    fn is_stale(&self, throttle: &LoginThrottleEntity, now: NaiveDateTime) -> bool {
+        throttle.last_failed_at + Duration::minutes(self.lockout_minutes) <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, NaiveDateTime};
+
+    use super::*;
+
+    fn policy() -> LoginThrottlePolicy {
+        LoginThrottlePolicy {
+            max_failures: 5,
+            base_delay_seconds: 2,
+            lockout_minutes: 15,
+        }
+    }
+
+    fn at(seconds: i64) -> NaiveDateTime {
+        DateTime::from_timestamp(1_800_000_000 + seconds, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn throttle(failed_count: i32, last_failed_at: NaiveDateTime) -> LoginThrottleEntity {
+        LoginThrottleEntity {
+            throttle_key: "username:alice".to_string(),
+            failed_count,
+            last_failed_at,
+            locked_until: None,
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_per_failure_up_to_the_cap() {
+        let policy = policy();
+
+        assert_eq!(policy.backoff(1), Duration::seconds(2));
+        assert_eq!(policy.backoff(2), Duration::seconds(4));
+        assert_eq!(policy.backoff(4), Duration::seconds(16));
+        assert_eq!(policy.backoff(30), Duration::seconds(MAX_BACKOFF_SECONDS));
+    }
+
+    #[test]
+    fn retry_after_waits_out_the_backoff() {
+        let policy = policy();
+        let throttle = throttle(3, at(0));
+
+        assert_eq!(policy.retry_after(&throttle, at(1)), Some(7));
+        assert_eq!(policy.retry_after(&throttle, at(8)), None);
+    }
+
+    #[test]
+    fn retry_after_reports_the_remaining_lockout() {
+        let policy = policy();
+        let throttle = LoginThrottleEntity {
+            locked_until: Some(at(600)),
+            ..throttle(5, at(0))
+        };
+
+        assert_eq!(policy.retry_after(&throttle, at(100)), Some(500));
+        assert_eq!(policy.retry_after(&throttle, at(600)), None);
+    }
+
+    #[test]
+    fn reaching_max_failures_locks_the_key() {
+        let policy = policy();
+        let current = throttle(4, at(0));
+
+        let (throttle, locked) = policy.register_failure("username:alice", Some(&current), at(60));
+
+        assert!(locked);
+        assert_eq!(throttle.failed_count, 5);
+        assert_eq!(throttle.locked_until, Some(at(60) + Duration::minutes(15)));
+    }
+
+    #[test]
+    fn failures_after_a_quiet_period_start_over() {
+        let policy = policy();
+        let current = throttle(4, at(0));
+
+        let (throttle, locked) =
+            policy.register_failure("username:alice", Some(&current), at(15 * 60));
+
+        assert!(!locked);
+        assert_eq!(throttle.failed_count, 1);
+    }
+
+    #[test]
+    fn failures_during_a_lockout_do_not_extend_it() {
+        let policy = policy();
+        let current = LoginThrottleEntity {
+            locked_until: Some(at(600)),
+            ..throttle(5, at(0))
+        };
+
+        let (throttle, locked) = policy.register_failure("username:alice", Some(&current), at(60));
+
+        assert!(!locked);
+        assert_eq!(throttle.failed_count, 6);
+        assert_eq!(throttle.locked_until, Some(at(600)));
+    }
+
+    #[test]
+    fn username_keys_fit_the_column() {
+        assert_eq!(
+            LoginThrottlePolicy::username_key("  Alice "),
+            "username:alice"
+        );
+
+        let key = LoginThrottlePolicy::username_key(&"a".repeat(10_000));
+        assert!(key.starts_with("username-digest:"));
+        assert!(key.chars().count() <= 320);
+        assert_eq!(key, LoginThrottlePolicy::username_key(&"A".repeat(10_000)));
+    }
+}
@@ -0,0 +1,86 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+pub enum MissionEventKinds {
+    Created,
+    Edited,
+    StatusChanged,
+    Joined,
+    Left,
+    Removed,
+    Restored,
+    ChiefTransferred,
+    CoChiefGranted,
+    CoChiefRevoked,
+}
+
+impl MissionEventKinds {
+    pub const ALL: [MissionEventKinds; 10] = [
+        MissionEventKinds::Created,
+        MissionEventKinds::Edited,
+        MissionEventKinds::StatusChanged,
+        MissionEventKinds::Joined,
+        MissionEventKinds::Left,
+        MissionEventKinds::Removed,
+        MissionEventKinds::Restored,
+        MissionEventKinds::ChiefTransferred,
+        MissionEventKinds::CoChiefGranted,
+        MissionEventKinds::CoChiefRevoked,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MissionEventKinds::Created => "Created",
+            MissionEventKinds::Edited => "Edited",
+            MissionEventKinds::StatusChanged => "StatusChanged",
+            MissionEventKinds::Joined => "Joined",
+            MissionEventKinds::Left => "Left",
+            MissionEventKinds::Removed => "Removed",
+            MissionEventKinds::Restored => "Restored",
+            MissionEventKinds::ChiefTransferred => "ChiefTransferred",
+            MissionEventKinds::CoChiefGranted => "CoChiefGranted",
+            MissionEventKinds::CoChiefRevoked => "CoChiefRevoked",
+        }
+    }
+}
+
+impl Display for MissionEventKinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MissionEventKinds {
+    type Err = anyhow::Error;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        MissionEventKinds::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == kind)
+            .ok_or_else(|| anyhow::anyhow!("Invalid mission event kind: {}", kind))
+    }
+}
+
+impl ToSql<Text, Pg> for MissionEventKinds {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Pg> for MissionEventKinds {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let kind = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        Ok(kind.parse()?)
+    }
+}
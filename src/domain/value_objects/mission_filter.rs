@@ -1,9 +1,277 @@
+use base64::{Engine, engine::general_purpose};
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::domain::{
+    errors::{DomainError, DomainResult, FieldError},
+    value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
+};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 100;
+pub const MAX_SEARCH_LENGTH: usize = 200;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MissionFilter {
     pub name: Option<String>,
+    /// Full-text search over name and description.
+    pub q: Option<String>,
     pub status: Option<MissionStatuses>,
-}
\ No newline at end of file
+    /// Archived missions are left off the board unless this is true.
+    pub include_archived: Option<bool>,
+    /// Cancelled missions are listed unless this is false.
+    pub include_cancelled: Option<bool>,
+    pub created_from: Option<NaiveDateTime>,
+    pub created_to: Option<NaiveDateTime>,
+    /// Missions that can still be joined and have room in the crew.
+    pub has_free_slots: Option<bool>,
+    /// Missions whose `starts_at` is still ahead.
+    pub upcoming: Option<bool>,
+    /// Open or in-progress missions whose deadline has passed.
+    pub overdue: Option<bool>,
+    /// Missions the caller chiefs. Needs a bearer token, like the two below.
+    pub chiefed_by_me: Option<bool>,
+    pub joined_by_me: Option<bool>,
+    pub exclude_joined: Option<bool>,
+    pub sort: Option<MissionSortKey>,
+    pub direction: Option<SortDirection>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub include_total: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionSortKey {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    CrewCount,
+    /// Only valid with `q`, and the default when `q` is given.
+    Relevance,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Position after the last mission of a page: its sort value, with the id
+/// breaking ties. The sort it was issued for travels with it so a cursor
+/// cannot be replayed against a different ordering.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionCursor {
+    pub sort: MissionSortKey,
+    pub direction: SortDirection,
+    pub value: CursorValue,
+    pub id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CursorValue {
+    Timestamp(NaiveDateTime),
+    Text(String),
+    Count(i64),
+    Rank(f32),
+}
+
+/// Who is listing missions and when, for filters that depend on the caller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissionViewer {
+    pub brawler_id: Option<i32>,
+    /// Application time the `upcoming` and `overdue` filters compare with,
+    /// the same clock the schedule is checked and expired against.
+    pub now: NaiveDateTime,
+}
+
+/// A validated `MissionFilter`, ready for the repository.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissionPageRequest {
+    pub sort: MissionSortKey,
+    pub direction: SortDirection,
+    pub cursor: Option<MissionCursor>,
+    pub limit: i64,
+    pub include_total: bool,
+    /// `q` rewritten for `to_tsquery`, every word matched as a prefix.
+    pub search: Option<String>,
+}
+
+impl MissionFilter {
+    pub fn needs_viewer(&self) -> bool {
+        [self.chiefed_by_me, self.joined_by_me, self.exclude_joined]
+            .iter()
+            .any(|flag| flag.unwrap_or(false))
+    }
+
+    /// Statuses left out of the listing. An explicit `status` overrides them.
+    pub fn hidden_statuses(&self) -> Vec<MissionStatuses> {
+        if self.status.is_some() {
+            return Vec::new();
+        }
+
+        let mut hidden = Vec::new();
+        if !self.include_archived.unwrap_or(false) {
+            hidden.push(MissionStatuses::Archived);
+        }
+        if !self.include_cancelled.unwrap_or(true) {
+            hidden.push(MissionStatuses::Cancelled);
+        }
+        hidden
+    }
+
+    pub fn page_request(&self) -> DomainResult<MissionPageRequest> {
+        let mut fields = Vec::new();
+
+        let search = match &self.q {
+            None => None,
+            Some(q) if q.chars().count() > MAX_SEARCH_LENGTH => {
+                fields.push(FieldError::new(
+                    "q",
+                    format!("Search must be at most {} characters", MAX_SEARCH_LENGTH),
+                ));
+                None
+            }
+            Some(q) => {
+                let search = prefix_tsquery(q);
+                if search.is_none() {
+                    fields.push(FieldError::new("q", "Search must contain at least one word"));
+                }
+                search
+            }
+        };
+
+        let sort = match self.sort {
+            Some(sort) => sort,
+            None if self.q.is_some() => MissionSortKey::Relevance,
+            None => MissionSortKey::default(),
+        };
+        if sort == MissionSortKey::Relevance && self.q.is_none() {
+            fields.push(FieldError::new("sort", "Sorting by relevance needs a search"));
+        }
+        let direction = self.direction.unwrap_or_default();
+
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            fields.push(FieldError::new(
+                "limit",
+                format!("Limit must be between 1 and {}", MAX_PAGE_SIZE),
+            ));
+        }
+
+        if let (Some(from), Some(to)) = (self.created_from, self.created_to)
+            && from > to
+        {
+            fields.push(FieldError::new(
+                "created_to",
+                "created_to must not be before created_from",
+            ));
+        }
+        let excluded_status = match self.status {
+            Some(MissionStatuses::Archived) => self.include_archived == Some(false),
+            Some(MissionStatuses::Cancelled) => self.include_cancelled == Some(false),
+            _ => false,
+        };
+        if excluded_status {
+            fields.push(FieldError::new(
+                "status",
+                "status names a status the filter excludes",
+            ));
+        }
+        if self.joined_by_me.unwrap_or(false) && self.exclude_joined.unwrap_or(false) {
+            fields.push(FieldError::new(
+                "exclude_joined",
+                "exclude_joined cannot be combined with joined_by_me",
+            ));
+        }
+
+        let cursor = match &self.cursor {
+            None => None,
+            Some(encoded) => match MissionCursor::decode(encoded) {
+                Some(cursor) if cursor.sort == sort && cursor.direction == direction => {
+                    Some(cursor)
+                }
+                Some(_) => {
+                    fields.push(FieldError::new(
+                        "cursor",
+                        "Cursor was issued for a different sort order",
+                    ));
+                    None
+                }
+                None => {
+                    fields.push(FieldError::new("cursor", "Cursor is invalid"));
+                    None
+                }
+            },
+        };
+
+        if !fields.is_empty() {
+            return Err(DomainError::invalid_fields("Mission filter is invalid", fields));
+        }
+
+        Ok(MissionPageRequest {
+            sort,
+            direction,
+            cursor,
+            limit,
+            include_total: self.include_total.unwrap_or(false),
+            search,
+        })
+    }
+}
+
+/// Keeps the letters and digits of each word, so user input can never be
+/// parsed as tsquery operators.
+fn prefix_tsquery(q: &str) -> Option<String> {
+    let terms: Vec<String> = q
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| format!("{}:*", word.to_lowercase()))
+        .collect();
+
+    (!terms.is_empty()).then(|| terms.join(" & "))
+}
+
+impl MissionCursor {
+    pub fn after(model: &MissionModel, sort: MissionSortKey, direction: SortDirection) -> Self {
+        let value = match sort {
+            MissionSortKey::CreatedAt => CursorValue::Timestamp(model.created_at),
+            MissionSortKey::UpdatedAt => CursorValue::Timestamp(model.updated_at),
+            MissionSortKey::Name => CursorValue::Text(model.name.clone()),
+            MissionSortKey::CrewCount => CursorValue::Count(model.crew_count),
+            MissionSortKey::Relevance => CursorValue::Rank(model.search_rank.unwrap_or_default()),
+        };
+
+        Self {
+            sort,
+            direction,
+            value,
+            id: model.id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serializes");
+        general_purpose::URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(encoded: &str) -> Option<Self> {
+        let json = general_purpose::URL_SAFE_NO_PAD.decode(encoded).ok()?;
+        let cursor: Self = serde_json::from_slice(&json).ok()?;
+
+        let value_matches_sort = matches!(
+            (cursor.sort, &cursor.value),
+            (
+                MissionSortKey::CreatedAt | MissionSortKey::UpdatedAt,
+                CursorValue::Timestamp(_)
+            ) | (MissionSortKey::Name, CursorValue::Text(_))
+                | (MissionSortKey::CrewCount, CursorValue::Count(_))
+                | (MissionSortKey::Relevance, CursorValue::Rank(_))
+        );
+
+        value_matches_sort.then_some(cursor)
+    }
+}
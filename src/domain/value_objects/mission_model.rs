@@ -1,9 +1,16 @@
 use chrono::NaiveDateTime;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 use crate::domain::{
-    entities::missions::{AddMissionEntity, EditMissionEntity},
-    value_objects::mission_statuses::MissionStatuses,
+    entities::{
+        mission_co_chiefs::AddMissionCoChiefEntity,
+        missions::{AddMissionEntity, EditMissionEntity},
+    },
+    value_objects::{
+        crew_bounds::CrewBounds, mission_event_kinds::MissionEventKinds,
+        mission_statuses::MissionStatuses,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -11,26 +18,48 @@ pub struct MissionModel {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub crew_count: i64,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub starts_at: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    /// Set only when listing with a search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_rank: Option<f32>,
+    /// Name and description excerpt with matches wrapped in `<mark>`. The
+    /// text is HTML-escaped, so `<mark>` is the only markup it contains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_snippet: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
+    /// Crew needed to start; defaults to the server minimum.
+    pub min_crew: Option<i32>,
+    /// Crew the mission takes; defaults to the server maximum.
+    pub max_crew: Option<i32>,
+    pub starts_at: Option<NaiveDateTime>,
+    /// An in-progress mission still running after this fails automatically.
+    pub deadline: Option<NaiveDateTime>,
 }
 
 impl AddMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> AddMissionEntity {
+    pub fn to_entity(&self, chief_id: i32, crew_bounds: &CrewBounds) -> AddMissionEntity {
         AddMissionEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            status: MissionStatuses::Open.to_string(),
+            status: MissionStatuses::Open,
             chief_id,
+            min_crew: self.min_crew.unwrap_or(crew_bounds.min_crew),
+            max_crew: self.max_crew.unwrap_or(crew_bounds.max_crew),
+            starts_at: self.starts_at,
+            deadline: self.deadline,
         }
     }
 }
@@ -39,14 +68,134 @@ impl AddMissionModel {
 pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    /// Left out to keep the start time, `null` to clear it.
+    #[serde(default, deserialize_with = "double_option")]
+    pub starts_at: Option<Option<NaiveDateTime>>,
+    /// Left out to keep the deadline, `null` to clear it.
+    #[serde(default, deserialize_with = "double_option")]
+    pub deadline: Option<Option<NaiveDateTime>>,
 }
 
 impl EditMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> EditMissionEntity {
+    pub fn to_entity(&self) -> EditMissionEntity {
         EditMissionEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            chief_id,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            starts_at: self.starts_at,
+            deadline: self.deadline,
+        }
+    }
+}
+
+/// Reads a present field, `null` included, as `Some`; `serde(default)` leaves
+/// a missing one `None`.
+fn double_option<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransferChiefModel {
+    /// Must already be in the crew; they leave it to become chief.
+    pub new_chief_id: i32,
+    /// Whether the old chief takes the new chief's crew slot. Defaults to true.
+    pub stay_in_crew: Option<bool>,
+}
+
+/// Permissions left out are granted.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GrantCoChiefModel {
+    pub can_start: Option<bool>,
+    pub can_complete: Option<bool>,
+    pub can_fail: Option<bool>,
+}
+
+impl GrantCoChiefModel {
+    pub fn to_entity(&self, mission_id: i32, brawler_id: i32) -> AddMissionCoChiefEntity {
+        AddMissionCoChiefEntity {
+            mission_id,
+            brawler_id,
+            can_start: self.can_start.unwrap_or(true),
+            can_complete: self.can_complete.unwrap_or(true),
+            can_fail: self.can_fail.unwrap_or(true),
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoChiefModel {
+    pub brawler_id: i32,
+    pub can_start: bool,
+    pub can_complete: bool,
+    pub can_fail: bool,
+    pub granted_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionEventModel {
+    pub id: i32,
+    pub actor_id: Option<i32>,
+    pub kind: MissionEventKinds,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: NaiveDateTime,
+}
+
+/// A removed mission waiting in the trash.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrashedMissionModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: MissionStatuses,
+    pub chief_id: i32,
+    pub deleted_at: NaiveDateTime,
+    /// The chief can no longer restore it after this.
+    pub restorable_until: NaiveDateTime,
+    /// When the purge job deletes it for good.
+    pub purged_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionPageModel {
+    pub items: Vec<MissionModel>,
+    pub next_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_tells_a_missing_time_from_a_cleared_one() {
+        let edit: EditMissionModel =
+            serde_json::from_str(r#"{"deadline": null, "starts_at": "2026-11-01T09:00:00"}"#)
+                .unwrap();
+        assert_eq!(edit.deadline, Some(None));
+        assert_eq!(
+            edit.starts_at,
+            Some(Some("2026-11-01T09:00:00".parse().unwrap()))
+        );
+
+        let edit: EditMissionModel = serde_json::from_str(r#"{"name": "Renamed"}"#).unwrap();
+        assert_eq!(edit.starts_at, None);
+        assert_eq!(edit.deadline, None);
+        assert!(!edit.to_entity().is_empty());
+    }
+
+    #[test]
+    fn clearing_a_time_is_a_change() {
+        let edit: EditMissionModel = serde_json::from_str(r#"{"deadline": null}"#).unwrap();
+
+        assert!(!edit.to_entity().is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Stage changes a chief can delegate to co-chiefs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MissionPermissions {
+    Start,
+    Complete,
+    Fail,
+}
+
+impl MissionPermissions {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MissionPermissions::Start => "start",
+            MissionPermissions::Complete => "complete",
+            MissionPermissions::Fail => "fail",
+        }
+    }
+}
+
+impl Display for MissionPermissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{Duration, NaiveDateTime};
+
+/// How long removed missions are kept, from the moment they were removed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissionRetention {
+    /// The chief may restore a removed mission within this many hours.
+    pub restore_window_hours: i64,
+    /// After this many days the mission and its history are deleted for good.
+    pub purge_after_days: i64,
+    /// How often the purge job looks for expired missions.
+    pub purge_interval_minutes: u64,
+}
+
+impl MissionRetention {
+    pub fn restorable_until(&self, deleted_at: NaiveDateTime) -> NaiveDateTime {
+        deleted_at + Duration::hours(self.restore_window_hours)
+    }
+
+    pub fn purged_at(&self, deleted_at: NaiveDateTime) -> NaiveDateTime {
+        deleted_at + Duration::days(self.purge_after_days)
+    }
+
+    /// Missions removed before this are due for purging.
+    pub fn purge_cutoff(&self, now: NaiveDateTime) -> NaiveDateTime {
+        now - Duration::days(self.purge_after_days)
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::NaiveDateTime;
+
+use crate::domain::errors::FieldError;
+
+/// When a mission is planned to start and when it must be done by.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MissionSchedule {
+    pub starts_at: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
+}
+
+/// An edit to a schedule: `None` keeps a time, `Some(None)` clears it.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MissionScheduleChanges {
+    pub starts_at: Option<Option<NaiveDateTime>>,
+    pub deadline: Option<Option<NaiveDateTime>>,
+}
+
+impl From<MissionSchedule> for MissionScheduleChanges {
+    /// Sets both times, as when a mission is first scheduled.
+    fn from(schedule: MissionSchedule) -> Self {
+        Self {
+            starts_at: Some(schedule.starts_at),
+            deadline: Some(schedule.deadline),
+        }
+    }
+}
+
+impl MissionSchedule {
+    /// Checks `changes` applied on top of `self`. Only a deadline that is
+    /// being set has to be in the future, so unrelated edits still pass once
+    /// an old deadline has gone by.
+    pub fn check_changes(
+        &self,
+        changes: &MissionScheduleChanges,
+        now: NaiveDateTime,
+    ) -> Vec<FieldError> {
+        let mut fields = Vec::new();
+
+        if let Some(Some(deadline)) = changes.deadline
+            && deadline <= now
+        {
+            fields.push(FieldError::new("deadline", "deadline must be in the future"));
+        }
+
+        let starts_at = changes.starts_at.unwrap_or(self.starts_at);
+        let deadline = changes.deadline.unwrap_or(self.deadline);
+        if let (Some(starts_at), Some(deadline)) = (starts_at, deadline)
+            && starts_at >= deadline
+        {
+            fields.push(FieldError::new(
+                "starts_at",
+                "starts_at must be before the deadline",
+            ));
+        }
+
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveDateTime};
+
+    use super::*;
+
+    fn at(hours: i64) -> NaiveDateTime {
+        DateTime::from_timestamp(1_800_000_000 + hours * 3600, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn scheduled() -> MissionSchedule {
+        MissionSchedule {
+            starts_at: Some(at(2)),
+            deadline: Some(at(4)),
+        }
+    }
+
+    #[test]
+    fn new_deadlines_must_be_in_the_future() {
+        let changes = MissionScheduleChanges {
+            deadline: Some(Some(at(-1))),
+            ..Default::default()
+        };
+
+        let fields = MissionSchedule::default().check_changes(&changes, at(0));
+
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field, "deadline");
+    }
+
+    #[test]
+    fn a_passed_deadline_left_alone_is_accepted() {
+        let fields = scheduled().check_changes(&MissionScheduleChanges::default(), at(10));
+
+        assert!(fields.is_empty());
+    }
+
+    #[test]
+    fn the_start_must_come_before_the_kept_deadline() {
+        let changes = MissionScheduleChanges {
+            starts_at: Some(Some(at(5))),
+            ..Default::default()
+        };
+
+        let fields = scheduled().check_changes(&changes, at(0));
+
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field, "starts_at");
+    }
+
+    #[test]
+    fn clearing_the_deadline_lifts_the_ordering_rule() {
+        let changes = MissionScheduleChanges {
+            starts_at: Some(Some(at(5))),
+            deadline: Some(None),
+        };
+
+        assert!(scheduled().check_changes(&changes, at(0)).is_empty());
+    }
+
+    #[test]
+    fn a_new_schedule_sets_both_times() {
+        let changes = MissionScheduleChanges::from(MissionSchedule {
+            starts_at: Some(at(3)),
+            deadline: None,
+        });
+
+        assert_eq!(changes.starts_at, Some(Some(at(3))));
+        assert_eq!(changes.deadline, Some(None));
+    }
+}
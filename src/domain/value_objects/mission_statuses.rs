@@ -1,23 +1,206 @@
-use std::fmt::Display;
+use std::{fmt::Display, io::Write, str::FromStr};
 
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Text,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+use crate::domain::errors::DomainError;
+
+/// Stored as text in `missions.status`, which a check constraint limits to
+/// these names.
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Text)]
 pub enum MissionStatuses {
     #[default]
     Open,
     InProgress,
     Completed,
     Failed,
+    /// Abandoned by the chief before it finished.
+    Cancelled,
+    /// Terminal; kept for history only.
+    Archived,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Mission cannot move from {from} to {to}")]
+pub struct IllegalTransition {
+    pub from: MissionStatuses,
+    pub to: MissionStatuses,
+}
+
+impl MissionStatuses {
+    pub const ALL: [MissionStatuses; 6] = [
+        MissionStatuses::Open,
+        MissionStatuses::InProgress,
+        MissionStatuses::Completed,
+        MissionStatuses::Failed,
+        MissionStatuses::Cancelled,
+        MissionStatuses::Archived,
+    ];
+
+    /// The transition table. Only a started mission can fail, and a failed
+    /// mission may be retried.
+    pub fn can_transition_to(self, to: MissionStatuses) -> bool {
+        use MissionStatuses::*;
+
+        matches!(
+            (self, to),
+            (Open, InProgress | Cancelled)
+                | (InProgress, Completed | Failed | Cancelled)
+                | (Failed, InProgress | Cancelled | Archived)
+                | (Completed, Archived)
+                | (Cancelled, Archived)
+        )
+    }
+
+    pub fn transition(self, to: MissionStatuses) -> Result<MissionStatuses, IllegalTransition> {
+        if self.can_transition_to(to) {
+            Ok(to)
+        } else {
+            Err(IllegalTransition { from: self, to })
+        }
+    }
+
+    /// Every status that may move to `self`.
+    pub fn sources(self) -> Vec<MissionStatuses> {
+        MissionStatuses::ALL
+            .into_iter()
+            .filter(|from| from.can_transition_to(self))
+            .collect()
+    }
+
+    /// Whether brawlers may join or leave the crew.
+    pub fn accepts_crew_changes(self) -> bool {
+        matches!(self, MissionStatuses::Open | MissionStatuses::Failed)
+    }
+
+    /// Whether the mission is over, so its leadership can no longer change.
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            MissionStatuses::Completed | MissionStatuses::Cancelled | MissionStatuses::Archived
+        )
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MissionStatuses::Open => "Open",
+            MissionStatuses::InProgress => "InProgress",
+            MissionStatuses::Completed => "Completed",
+            MissionStatuses::Failed => "Failed",
+            MissionStatuses::Cancelled => "Cancelled",
+            MissionStatuses::Archived => "Archived",
+        }
+    }
 }
 
 impl Display for MissionStatuses {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            MissionStatuses::Open => write!(f, "Open"),
-            MissionStatuses::InProgress => write!(f, "InProgress"),
-            MissionStatuses::Completed => write!(f, "Completed"),
-            MissionStatuses::Failed => write!(f, "Failed"),
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MissionStatuses {
+    type Err = anyhow::Error;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        MissionStatuses::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == status)
+            .ok_or_else(|| anyhow::anyhow!("Invalid mission status: {}", status))
+    }
+}
+
+impl ToSql<Text, Pg> for MissionStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Pg> for MissionStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        Ok(status.parse()?)
+    }
+}
+
+impl From<IllegalTransition> for DomainError {
+    fn from(error: IllegalTransition) -> Self {
+        DomainError::conflict(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MissionStatuses::{self, *};
+
+    #[test]
+    fn allows_exactly_the_listed_transitions() {
+        let allowed = [
+            (Open, InProgress),
+            (Open, Cancelled),
+            (InProgress, Completed),
+            (InProgress, Failed),
+            (InProgress, Cancelled),
+            (Failed, InProgress),
+            (Failed, Cancelled),
+            (Failed, Archived),
+            (Completed, Archived),
+            (Cancelled, Archived),
+        ];
+
+        for from in MissionStatuses::ALL {
+            for to in MissionStatuses::ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from, to)),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn open_missions_cannot_fail() {
+        let error = Open.transition(Failed).unwrap_err();
+
+        assert_eq!(error.from, Open);
+        assert_eq!(error.to, Failed);
+        assert_eq!(error.to_string(), "Mission cannot move from Open to Failed");
+    }
+
+    #[test]
+    fn archived_is_terminal() {
+        assert!(
+            MissionStatuses::ALL
+                .into_iter()
+                .all(|to| !Archived.can_transition_to(to))
+        );
+    }
+
+    #[test]
+    fn sources_lists_every_status_that_can_reach_the_target() {
+        assert_eq!(Failed.sources(), vec![InProgress]);
+        assert_eq!(Archived.sources(), vec![Completed, Failed, Cancelled]);
+        assert!(Open.sources().is_empty());
+    }
+
+    #[test]
+    fn status_names_round_trip() {
+        for status in MissionStatuses::ALL {
+            assert_eq!(status.as_str().parse::<MissionStatuses>().unwrap(), status);
         }
+        assert!("Done".parse::<MissionStatuses>().is_err());
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,18 @@
 pub mod brawler_model;
+pub mod brawler_roles;
+pub mod crew_bounds;
+pub mod login_throttle_policy;
+pub mod mission_event_kinds;
 pub mod mission_filter;
 pub mod mission_model;
+pub mod mission_permissions;
+pub mod mission_retention;
+pub mod mission_schedule;
 pub mod mission_statuses;
+pub mod password_models;
+pub mod password_policy;
+pub mod registration_policy;
+pub mod session_model;
+pub mod two_factor_models;
 pub mod uploaded_image;
 pub mod base64_image;
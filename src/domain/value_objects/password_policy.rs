@@ -0,0 +1,97 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use crate::domain::errors::FieldError;
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+
+// Frequently breached passwords, one per line and lowercase. Kept in the binary
+// so the check works without network access.
+static COMMON_PASSWORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    include_str!("common_passwords.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+});
+
+/// Checks a new password for `username`, reporting problems against `field`.
+pub fn validate_password(field: &str, password: &str, username: &str) -> Result<(), FieldError> {
+    let length = password.chars().count();
+
+    if length < MIN_PASSWORD_LENGTH {
+        return Err(FieldError::new(
+            field,
+            format!("Password must be at least {} characters long", MIN_PASSWORD_LENGTH),
+        ));
+    }
+    if length > MAX_PASSWORD_LENGTH {
+        return Err(FieldError::new(
+            field,
+            format!("Password must be at most {} characters long", MAX_PASSWORD_LENGTH),
+        ));
+    }
+    if !username.is_empty() && password.to_lowercase().contains(&username.to_lowercase()) {
+        return Err(FieldError::new(field, "Password must not contain the username"));
+    }
+    if COMMON_PASSWORDS.contains(password.to_lowercase().as_str()) {
+        return Err(FieldError::new(
+            field,
+            "Password is too common, choose a less predictable one",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejection(password: &str, username: &str) -> Option<String> {
+        validate_password("password", password, username)
+            .err()
+            .map(|error| error.message)
+    }
+
+    #[test]
+    fn accepts_a_reasonable_password() {
+        assert_eq!(rejection("Brawl3r-Pass!", "menta"), None);
+    }
+
+    #[test]
+    fn rejects_passwords_outside_the_length_bounds() {
+        let too_long = "x".repeat(MAX_PASSWORD_LENGTH + 1);
+
+        assert!(rejection("Ab3!xyz", "menta").unwrap().contains("at least"));
+        assert_eq!(rejection("Ab3!xyzw", "menta"), None);
+        assert!(rejection(&too_long, "menta").unwrap().contains("at most"));
+    }
+
+    #[test]
+    fn counts_characters_rather_than_bytes() {
+        assert!(rejection("ééééééé", "menta").is_some());
+        assert_eq!(rejection("éééééééé", "menta"), None);
+    }
+
+    #[test]
+    fn rejects_common_passwords_in_any_case() {
+        assert!(rejection("password1", "menta").unwrap().contains("too common"));
+        assert!(rejection("QwertyUIOP", "menta").unwrap().contains("too common"));
+    }
+
+    #[test]
+    fn rejects_passwords_containing_the_username() {
+        let message = rejection("xx-Menta-2026", "menta").unwrap();
+        assert_eq!(message, "Password must not contain the username");
+
+        // Without a username there is nothing to compare against.
+        assert_eq!(rejection("xx-Menta-2026", ""), None);
+    }
+
+    #[test]
+    fn reports_the_given_field() {
+        let error = validate_password("new_password", "short", "menta").unwrap_err();
+        assert_eq!(error.field, "new_password");
+    }
+}
@@ -0,0 +1,239 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::domain::errors::FieldError;
+
+pub const MIN_USERNAME_LENGTH: usize = 3;
+pub const MAX_USERNAME_LENGTH: usize = 32;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+pub const MAX_EMAIL_LENGTH: usize = 255;
+
+// Names that could be mistaken for staff or collide with routes.
+const RESERVED_USERNAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "api",
+    "brawler",
+    "brawlers",
+    "chief",
+    "me",
+    "mod",
+    "moderator",
+    "null",
+    "root",
+    "staff",
+    "support",
+    "system",
+    "undefined",
+];
+
+/// Returns the trimmed username. Letters, digits, `_`, `.` and `-` are allowed
+/// and it has to start with a letter or digit.
+pub fn validate_username(username: &str) -> Result<String, FieldError> {
+    let username = username.trim();
+    let length = username.chars().count();
+
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length) {
+        return Err(FieldError::new(
+            "username",
+            format!(
+                "Username must be between {} and {} characters long",
+                MIN_USERNAME_LENGTH, MAX_USERNAME_LENGTH
+            ),
+        ));
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    {
+        return Err(FieldError::new(
+            "username",
+            "Username may only contain letters, digits, '_', '.' and '-'",
+        ));
+    }
+    if !username.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err(FieldError::new(
+            "username",
+            "Username must start with a letter or digit",
+        ));
+    }
+    if RESERVED_USERNAMES.contains(&username.to_lowercase().as_str()) {
+        return Err(FieldError::new("username", "Username is reserved"));
+    }
+
+    Ok(username.to_string())
+}
+
+/// Returns the display name NFC normalized, trimmed and with inner runs of
+/// whitespace collapsed, so visually identical names are stored identically.
+pub fn normalize_display_name(display_name: &str) -> Result<String, FieldError> {
+    let normalized: String = display_name.nfc().collect();
+
+    if normalized
+        .chars()
+        .any(|c| c.is_control() && !c.is_whitespace())
+    {
+        return Err(FieldError::new(
+            "display_name",
+            "Display name must not contain control characters",
+        ));
+    }
+
+    let display_name = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+    let length = display_name.chars().count();
+
+    if length == 0 {
+        return Err(FieldError::new("display_name", "Display name is required"));
+    }
+    if length > MAX_DISPLAY_NAME_LENGTH {
+        return Err(FieldError::new(
+            "display_name",
+            format!(
+                "Display name must be at most {} characters long",
+                MAX_DISPLAY_NAME_LENGTH
+            ),
+        ));
+    }
+
+    Ok(display_name)
+}
+
+/// Returns the trimmed, lowercased email after a basic shape check.
+pub fn normalize_email(email: &str) -> Result<String, FieldError> {
+    let email = email.trim().to_lowercase();
+
+    let valid_shape = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !domain.contains('@')
+        }
+        None => false,
+    };
+
+    if !valid_shape || email.chars().any(char::is_whitespace) {
+        return Err(FieldError::new("email", "Email address is invalid"));
+    }
+    if email.len() > MAX_EMAIL_LENGTH {
+        return Err(FieldError::new(
+            "email",
+            format!("Email must be at most {} characters long", MAX_EMAIL_LENGTH),
+        ));
+    }
+
+    Ok(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn username_rejection(username: &str) -> Option<String> {
+        validate_username(username).err().map(|error| error.message)
+    }
+
+    #[test]
+    fn accepts_and_trims_a_reasonable_username() {
+        assert_eq!(validate_username("  menta_01 ").unwrap(), "menta_01");
+        assert_eq!(validate_username("a.b-c").unwrap(), "a.b-c");
+    }
+
+    #[test]
+    fn rejects_usernames_outside_the_length_bounds() {
+        let longest = "x".repeat(MAX_USERNAME_LENGTH);
+        let too_long = "x".repeat(MAX_USERNAME_LENGTH + 1);
+
+        assert!(username_rejection("ab").unwrap().contains("between"));
+        assert_eq!(username_rejection("abc"), None);
+        assert_eq!(username_rejection(&longest), None);
+        assert!(username_rejection(&too_long).unwrap().contains("between"));
+        // Surrounding whitespace does not count towards the length.
+        assert!(username_rejection("  ab  ").is_some());
+    }
+
+    #[test]
+    fn rejects_characters_outside_the_charset() {
+        assert!(
+            username_rejection("men ta")
+                .unwrap()
+                .contains("may only contain")
+        );
+        assert!(
+            username_rejection("menta!")
+                .unwrap()
+                .contains("may only contain")
+        );
+        assert!(
+            username_rejection("mentá")
+                .unwrap()
+                .contains("may only contain")
+        );
+        assert!(username_rejection("_menta").unwrap().contains("must start"));
+        assert!(username_rejection(".menta").unwrap().contains("must start"));
+    }
+
+    #[test]
+    fn rejects_reserved_usernames_in_any_case() {
+        assert_eq!(username_rejection("admin").unwrap(), "Username is reserved");
+        assert_eq!(
+            username_rejection("Moderator").unwrap(),
+            "Username is reserved"
+        );
+        assert_eq!(username_rejection("admins"), None);
+    }
+
+    #[test]
+    fn display_names_are_nfc_normalized() {
+        let decomposed = "Mente\u{0301}";
+
+        assert_eq!(normalize_display_name(decomposed).unwrap(), "Ment\u{e9}");
+    }
+
+    #[test]
+    fn display_names_have_whitespace_collapsed() {
+        assert_eq!(
+            normalize_display_name("  Big \t Brawler\n Menta ").unwrap(),
+            "Big Brawler Menta"
+        );
+        assert!(normalize_display_name(" \t\n ").is_err());
+        assert!(normalize_display_name("Menta\u{7}").is_err());
+    }
+
+    #[test]
+    fn display_names_are_bounded_in_characters() {
+        let longest = "é".repeat(MAX_DISPLAY_NAME_LENGTH);
+        let too_long = "é".repeat(MAX_DISPLAY_NAME_LENGTH + 1);
+
+        assert_eq!(normalize_display_name(&longest).unwrap(), longest);
+        assert!(normalize_display_name(&too_long).is_err());
+        // A run of whitespace counts as a single character.
+        let padded = format!("{}     {}", "x".repeat(24), "x".repeat(24));
+        assert!(normalize_display_name(&padded).is_ok());
+    }
+
+    #[test]
+    fn emails_are_trimmed_and_lowercased() {
+        assert_eq!(
+            normalize_email("  Menta@Example.COM \n").unwrap(),
+            "menta@example.com"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_or_overlong_emails() {
+        let too_long = format!("{}@example.com", "x".repeat(MAX_EMAIL_LENGTH));
+
+        assert!(normalize_email("menta").is_err());
+        assert!(normalize_email("@example.com").is_err());
+        assert!(normalize_email("menta@example").is_err());
+        assert!(normalize_email("menta@.example.com").is_err());
+        assert!(normalize_email("men ta@example.com").is_err());
+        assert!(
+            normalize_email(&too_long)
+                .unwrap_err()
+                .message
+                .contains("at most")
+        );
+    }
+}
@@ -83,4 +83,35 @@ pub async fn upload(
     let json: UploadedImage =
         serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
     Ok(json)
-}
\ No newline at end of file
+}
+
+/// Deletes an uploaded image; used to clean up after a failed write.
+pub async fn destroy(public_id: &str) -> Result<()> {
+    let cloud_env = get_cloudinary_env()?;
+    let timestamp = Utc::now().timestamp().to_string();
+
+    let mut hasher = Sha1::new();
+    hasher.update(format!("public_id={}&timestamp={}", public_id, timestamp));
+    hasher.update(cloud_env.api_secret.clone());
+
+    let form = Form::new()
+        .text("public_id", public_id.to_string())
+        .text("timestamp", timestamp)
+        .text("api_key", cloud_env.api_key.clone())
+        .text("signature", format!("{:x}", hasher.finalize()));
+    let client = reqwest::Client::new();
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/image/destroy",
+        cloud_env.cloud_name
+    );
+
+    client
+        .post(&url)
+        .multipart(form)
+        .send()
+        .await
+        .context(format!("destroy via {}", url))?
+        .error_for_status()?;
+
+    Ok(())
+}
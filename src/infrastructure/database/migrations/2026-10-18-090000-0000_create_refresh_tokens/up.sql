@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE refresh_tokens (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    token_hash VARCHAR(64) NOT NULL,
+    family_id VARCHAR(64) NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP,
+    replaced_by INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    refresh_tokens
+ADD
+    CONSTRAINT fk_refresh_token_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT unique_refresh_token_hash UNIQUE (token_hash);
+
+CREATE INDEX idx_refresh_tokens_family_id ON refresh_tokens (family_id);
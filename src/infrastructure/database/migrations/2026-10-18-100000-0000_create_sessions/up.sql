@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE sessions (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    jti VARCHAR(64) NOT NULL,
+    device_name VARCHAR(255),
+    user_agent VARCHAR(512),
+    ip_address VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP
+);
+
+ALTER TABLE
+    sessions
+ADD
+    CONSTRAINT fk_session_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT unique_session_jti UNIQUE (jti);
+
+CREATE INDEX idx_sessions_brawler_id ON sessions (brawler_id);
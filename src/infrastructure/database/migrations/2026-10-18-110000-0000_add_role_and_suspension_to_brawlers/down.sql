@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE brawlers
+DROP CONSTRAINT check_brawler_role,
+DROP COLUMN suspended_at,
+DROP COLUMN "role";
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN "role" VARCHAR(20) NOT NULL DEFAULT 'Brawler',
+ADD COLUMN suspended_at TIMESTAMP,
+ADD CONSTRAINT check_brawler_role CHECK ("role" IN ('Brawler', 'Moderator', 'Admin'));
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS lockout_audits;
+
+DROP TABLE IF EXISTS login_throttles;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE login_throttles (
+    throttle_key VARCHAR(320) PRIMARY KEY,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    last_failed_at TIMESTAMP NOT NULL DEFAULT now(),
+    locked_until TIMESTAMP
+);
+
+CREATE TABLE lockout_audits (
+    id SERIAL PRIMARY KEY,
+    throttle_key VARCHAR(320) NOT NULL,
+    failed_count INTEGER NOT NULL,
+    locked_until TIMESTAMP NOT NULL,
+    ip_address VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_lockout_audits_throttle_key ON lockout_audits (throttle_key);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS password_reset_tokens;
+
+ALTER TABLE brawlers
+DROP CONSTRAINT unique_brawler_email,
+DROP COLUMN email;
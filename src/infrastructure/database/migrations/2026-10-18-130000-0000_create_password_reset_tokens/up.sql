@@ -0,0 +1,22 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN email VARCHAR(255),
+ADD CONSTRAINT unique_brawler_email UNIQUE (email);
+
+CREATE TABLE password_reset_tokens (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    token_hash VARCHAR(64) NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    used_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    password_reset_tokens
+ADD
+    CONSTRAINT fk_password_reset_token_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT unique_password_reset_token_hash UNIQUE (token_hash);
+
+CREATE INDEX idx_password_reset_tokens_brawler_id ON password_reset_tokens (brawler_id);
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS recovery_codes;
+
+ALTER TABLE brawlers
+DROP COLUMN totp_last_used_step,
+DROP COLUMN totp_enabled_at,
+DROP COLUMN totp_secret;
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN totp_secret VARCHAR(64),
+ADD COLUMN totp_enabled_at TIMESTAMP,
+ADD COLUMN totp_last_used_step BIGINT;
+
+CREATE TABLE recovery_codes (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    code_hash VARCHAR(255) NOT NULL,
+    used_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    recovery_codes
+ADD
+    CONSTRAINT fk_recovery_code_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_recovery_codes_brawler_id ON recovery_codes (brawler_id);
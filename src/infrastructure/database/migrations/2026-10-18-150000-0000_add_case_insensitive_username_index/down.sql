@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS unique_brawler_username_lower;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE UNIQUE INDEX unique_brawler_username_lower ON brawlers (LOWER(username));
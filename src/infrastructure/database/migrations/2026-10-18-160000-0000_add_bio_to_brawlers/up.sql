@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN bio VARCHAR(500);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS missions_live_name_id;
+DROP INDEX IF EXISTS missions_live_updated_at_id;
+DROP INDEX IF EXISTS missions_live_created_at_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX missions_live_created_at_id ON missions (created_at, id) WHERE deleted_at IS NULL;
+CREATE INDEX missions_live_updated_at_id ON missions (updated_at, id) WHERE deleted_at IS NULL;
+CREATE INDEX missions_live_name_id ON missions (name, id) WHERE deleted_at IS NULL;
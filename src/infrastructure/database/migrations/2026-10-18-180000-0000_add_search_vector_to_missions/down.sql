@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS missions_search_vector;
+ALTER TABLE missions DROP COLUMN IF EXISTS search_vector;
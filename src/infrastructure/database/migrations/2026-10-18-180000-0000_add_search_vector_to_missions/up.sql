@@ -0,0 +1,10 @@
+-- Your SQL goes here
+-- The 'simple' configuration skips stemming, so prefix queries match the
+-- words exactly as they were typed.
+ALTER TABLE missions
+ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', coalesce(name, '')), 'A') ||
+    setweight(to_tsvector('simple', coalesce(description, '')), 'B')
+) STORED;
+
+CREATE INDEX missions_search_vector ON missions USING GIN (search_vector);
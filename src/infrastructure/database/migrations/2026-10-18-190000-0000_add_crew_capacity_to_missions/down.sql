@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions
+DROP CONSTRAINT IF EXISTS missions_crew_capacity,
+DROP COLUMN IF EXISTS max_crew,
+DROP COLUMN IF EXISTS min_crew;
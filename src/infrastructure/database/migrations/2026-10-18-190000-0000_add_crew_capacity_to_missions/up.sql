@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN min_crew INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN max_crew INTEGER NOT NULL DEFAULT 10,
+ADD CONSTRAINT missions_crew_capacity CHECK (min_crew >= 1 AND min_crew <= max_crew);
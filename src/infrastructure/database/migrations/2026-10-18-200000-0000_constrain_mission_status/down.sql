@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions DROP CONSTRAINT IF EXISTS missions_status_valid;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD CONSTRAINT missions_status_valid CHECK (
+    status IN ('Open', 'InProgress', 'Completed', 'Failed', 'Cancelled', 'Archived')
+);
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE mission_events (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    actor_id INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    kind VARCHAR(32) NOT NULL,
+    before JSONB,
+    after JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX mission_events_mission_id_created_at ON mission_events (mission_id, created_at, id);
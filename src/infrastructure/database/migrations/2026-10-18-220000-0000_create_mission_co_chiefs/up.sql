@@ -0,0 +1,13 @@
+-- Your SQL goes here
+-- A co-chief is always a crew member, so leaving the crew ends the delegation.
+CREATE TABLE mission_co_chiefs (
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    can_start BOOLEAN NOT NULL DEFAULT TRUE,
+    can_complete BOOLEAN NOT NULL DEFAULT TRUE,
+    can_fail BOOLEAN NOT NULL DEFAULT TRUE,
+    granted_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, brawler_id),
+    FOREIGN KEY (mission_id, brawler_id)
+        REFERENCES crew_memberships (mission_id, brawler_id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- Serves the trash listing and the purge job, which only look at removed missions.
+CREATE INDEX missions_deleted_at ON missions (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS missions_in_progress_deadline;
+
+ALTER TABLE missions
+    DROP CONSTRAINT IF EXISTS missions_starts_before_deadline,
+    DROP COLUMN IF EXISTS deadline,
+    DROP COLUMN IF EXISTS starts_at;
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TABLE missions
+    ADD COLUMN starts_at TIMESTAMP,
+    ADD COLUMN deadline TIMESTAMP,
+    ADD CONSTRAINT missions_starts_before_deadline
+        CHECK (starts_at IS NULL OR deadline IS NULL OR starts_at < deadline);
+
+-- The expiry scheduler only scans running missions that have a deadline.
+CREATE INDEX missions_in_progress_deadline ON missions (deadline)
+    WHERE status = 'InProgress' AND deadline IS NOT NULL AND deleted_at IS NULL;
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, dsl::update,
+};
+use serde_json::json;
+
+use crate::{
+    domain::{
+        entities::{
+            mission_events::AddMissionEventEntity,
+            missions::{CancelledMissionEntity, MissionEntity},
+        },
+        errors::DomainError,
+        repositories::administration::AdministrationRepository,
+        value_objects::{mission_event_kinds::MissionEventKinds, mission_statuses::MissionStatuses},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            mission_events::record_event,
+            mission_locks::lock_mission,
+            mission_operation::{change_status, release_crew},
+        },
+        schema::{brawlers, missions},
+    },
+};
+
+pub struct AdministrationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AdministrationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AdministrationRepository for AdministrationPostgres {
+    async fn force_close_mission(
+        &self,
+        mission_id: i32,
+        moderator_id: i32,
+    ) -> Result<CancelledMissionEntity> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        // The row lock is held while blocking, so keep it off the async workers.
+        let result = tokio::task::spawn_blocking(move || -> Result<CancelledMissionEntity> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let (mission, _crew_count) = lock_mission(conn, mission_id)?;
+                // Checked before the crew goes, so a refused close leaves it intact.
+                mission
+                    .status
+                    .transition(MissionStatuses::Cancelled)
+                    .map_err(DomainError::from)?;
+
+                let released_crew = release_crew(conn, mission_id)?;
+                let released_ids: Vec<i32> =
+                    released_crew.iter().map(|brawler| brawler.id).collect();
+                change_status(
+                    conn,
+                    &mission,
+                    Some(moderator_id),
+                    MissionStatuses::Cancelled,
+                    json!({ "status": MissionStatuses::Cancelled, "released_crew": released_ids }),
+                )?;
+
+                Ok(CancelledMissionEntity {
+                    mission,
+                    released_crew,
+                })
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn restore_mission(&self, mission_id: i32, admin_id: i32) -> Result<i32> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> Result<i32> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let deleted_at = missions::table
+                    .filter(missions::id.eq(mission_id))
+                    .filter(missions::deleted_at.is_not_null())
+                    .select(missions::deleted_at)
+                    .for_update()
+                    .first::<Option<NaiveDateTime>>(conn)?;
+
+                let mission = update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .set(missions::deleted_at.eq(None::<NaiveDateTime>))
+                    .returning(MissionEntity::as_returning())
+                    .get_result::<MissionEntity>(conn)?;
+
+                record_event(
+                    conn,
+                    AddMissionEventEntity {
+                        mission_id,
+                        actor_id: Some(admin_id),
+                        kind: MissionEventKinds::Restored,
+                        before: Some(json!({ "deleted_at": deleted_at })),
+                        after: Some(mission.snapshot()),
+                    },
+                )?;
+
+                Ok(mission.id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn suspend_brawler(&self, brawler_id: i32, suspended_at: NaiveDateTime) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::suspended_at.eq(suspended_at))
+            .returning(brawlers::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn unsuspend_brawler(&self, brawler_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::suspended_at.eq(None::<NaiveDateTime>))
+            .returning(brawlers::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -1,19 +1,32 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use diesel::{
-    ExpressionMethods, RunQueryDsl, SelectableHelper, insert_into,
+    ExpressionMethods, PgConnection,
+    connection::{AnsiTransactionManager, TransactionManager}, RunQueryDsl, SelectableHelper, dsl::exists, insert_into, select,
     query_dsl::methods::{FilterDsl, SelectDsl},
+    sql_types::Varchar,
 };
 use std::sync::Arc;
+use tracing::error;
 
 use crate::{
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
-        repositories::brawlers::BrawlerRepository, value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+        entities::brawlers::{BrawlerEntity, EditProfileEntity, RegisterBrawlerEntity},
+        repositories::brawlers::BrawlerRepository, value_objects::{base64_image::Base64Image, brawler_model::MissionStatsModel, uploaded_image::UploadedImage},
     },
     infrastructure::{cloudinary::UploadImageOptions, database::{postgresql_connection::PgPoolSquad, schema::brawlers}},
 };
 
+diesel::define_sql_function! {
+    fn lower(value: Varchar) -> Varchar;
+}
+
+fn rollback(connection: &mut PgConnection) {
+    if let Err(e) = AnsiTransactionManager::rollback_transaction(connection) {
+        error!("Failed to roll back brawler registration: {}", e);
+    }
+}
+
 pub struct BrawlerPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -37,16 +50,186 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(result)
     }
 
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity> {
+    async fn register_with_avatar(
+        &self,
+        register_brawler_entity: RegisterBrawlerEntity,
+        base64_image: Base64Image,
+        mut option: UploadImageOptions,
+    ) -> Result<(i32, UploadedImage)> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        // The transaction is driven by hand because the upload has to be
+        // awaited between the insert and the commit.
+        AnsiTransactionManager::begin_transaction(&mut *connection)?;
+
+        let brawler_id = match insert_into(brawlers::table)
+            .values(&register_brawler_entity)
+            .returning(brawlers::id)
+            .get_result::<i32>(&mut *connection)
+        {
+            Ok(brawler_id) => brawler_id,
+            Err(e) => {
+                rollback(&mut connection);
+                return Err(e.into());
+            }
+        };
+
+        option.public_id = Some(brawler_id.to_string());
+        let uploaded_image =
+            match crate::infrastructure::cloudinary::upload(base64_image, option).await {
+                Ok(uploaded_image) => uploaded_image,
+                Err(e) => {
+                    rollback(&mut connection);
+                    return Err(e);
+                }
+            };
+
+        let stored = diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set((
+                brawlers::avatar_url.eq(uploaded_image.url.clone()),
+                brawlers::avatar_public_id.eq(uploaded_image.public_id.clone()),
+            ))
+            .execute(&mut *connection)
+            .and_then(|_| AnsiTransactionManager::commit_transaction(&mut *connection));
+
+        if let Err(e) = stored {
+            rollback(&mut connection);
+            if let Err(destroy_error) =
+                crate::infrastructure::cloudinary::destroy(&uploaded_image.public_id).await
+            {
+                error!(
+                    "Failed to remove avatar {} after a failed registration: {}",
+                    uploaded_image.public_id, destroy_error
+                );
+            }
+            return Err(e.into());
+        }
+
+        Ok((brawler_id, uploaded_image))
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .filter(lower(brawlers::username).eq(username.to_lowercase()))
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn username_exists(&self, username: &str) -> Result<bool> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = select(exists(
+            brawlers::table.filter(lower(brawlers::username).eq(username.to_lowercase())),
+        ))
+        .get_result::<bool>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn email_exists(&self, email: &str) -> Result<bool> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = select(exists(
+            brawlers::table.filter(brawlers::email.eq(email.trim().to_lowercase())),
+        ))
+        .get_result::<bool>(&mut connection)?;
+
+        Ok(result)
+    }
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .filter(brawlers::id.eq(brawler_id))
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<BrawlerEntity> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         let result = brawlers::table
-            .filter(brawlers::username.eq(username))
+            .filter(brawlers::email.eq(email.trim().to_lowercase()))
             .select(BrawlerEntity::as_select())
             .first::<BrawlerEntity>(&mut connection)?;
 
         Ok(result)
     }
+
+    async fn update_profile(
+        &self,
+        brawler_id: i32,
+        edit_profile_entity: EditProfileEntity,
+    ) -> Result<BrawlerEntity> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        // An empty changeset is not valid SQL, so a no-op edit just reads back.
+        if edit_profile_entity.display_name.is_none() && edit_profile_entity.bio.is_none() {
+            let result = brawlers::table
+                .filter(brawlers::id.eq(brawler_id))
+                .select(BrawlerEntity::as_select())
+                .first::<BrawlerEntity>(&mut connection)?;
+            return Ok(result);
+        }
+
+        let result = diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(&edit_profile_entity)
+            .returning(BrawlerEntity::as_returning())
+            .get_result::<BrawlerEntity>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn mission_stats(&self, brawler_id: i32) -> Result<MissionStatsModel> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            WITH involved AS (
+                SELECT m.id, m.status, m.chief_id = $1 AS is_chief
+                FROM missions m
+                WHERE m.deleted_at IS NULL
+                  AND (
+                      m.chief_id = $1
+                      OR EXISTS (
+                          SELECT 1 FROM crew_memberships cm
+                          WHERE cm.mission_id = m.id AND cm.brawler_id = $1
+                      )
+                  )
+            )
+            SELECT
+                COUNT(*) FILTER (WHERE is_chief) AS chiefed_count,
+                COUNT(*) FILTER (WHERE NOT is_chief) AS joined_count,
+                COUNT(*) FILTER (WHERE status = 'Completed') AS completed_count,
+                COUNT(*) FILTER (WHERE status = 'Failed') AS failed_count
+            FROM involved
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<MissionStatsModel>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn update_password(&self, brawler_id: i32, password: String) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::password.eq(password))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
     async fn upload_avatar(
         &self,
         brawler_id: i32,
@@ -1,14 +1,22 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
+use diesel::{Connection, ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
+use serde_json::json;
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMembershipEntity,
-        repositories::crew_operation::CrewOperationRepository,
+        entities::{
+            crew_memberships::CrewMembershipEntity, mission_events::AddMissionEventEntity,
+        },
+        repositories::{crew_operation::CrewOperationRepository, mission_operation::MissionGuard},
+        value_objects::mission_event_kinds::MissionEventKinds,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{mission_events::record_event, mission_locks::lock_mission},
+        schema::crew_memberships,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
 };
 
 pub struct CrewOperationPostgres {
@@ -23,20 +31,81 @@ impl CrewOperationPostgres {
 
 #[async_trait]
 impl CrewOperationRepository for CrewOperationPostgres {
-    async fn join(&self, crew_member_ships: CrewMembershipEntity) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        insert_into(crew_memberships::table)
-            .values(crew_member_ships)
-            .execute(&mut conn)?;
+    async fn join(
+        &self,
+        crew_member_ships: CrewMembershipEntity,
+        guard: impl MissionGuard,
+    ) -> Result<()> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        // The row lock is held while blocking, so keep it off the async workers.
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let (mission, crew_count) = lock_mission(conn, crew_member_ships.mission_id)?;
+                guard(&mission, crew_count)?;
+
+                insert_into(crew_memberships::table)
+                    .values(&crew_member_ships)
+                    .execute(conn)?;
+
+                record_event(
+                    conn,
+                    AddMissionEventEntity {
+                        mission_id: crew_member_ships.mission_id,
+                        actor_id: Some(crew_member_ships.brawler_id),
+                        kind: MissionEventKinds::Joined,
+                        before: None,
+                        after: Some(json!({ "brawler_id": crew_member_ships.brawler_id })),
+                    },
+                )?;
+
+                Ok(())
+            })
+        })
+        .await??;
+
         Ok(())
     }
 
-    async fn leave(&self, crew_member_ships: CrewMembershipEntity) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        delete(crew_memberships::table)
-            .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
-            .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
-            .execute(&mut conn)?;
+    async fn leave(
+        &self,
+        crew_member_ships: CrewMembershipEntity,
+        guard: impl MissionGuard,
+    ) -> Result<()> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let (mission, crew_count) = lock_mission(conn, crew_member_ships.mission_id)?;
+                guard(&mission, crew_count)?;
+
+                let removed = delete(crew_memberships::table)
+                    .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+                    .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+                    .execute(conn)?;
+
+                if removed > 0 {
+                    record_event(
+                        conn,
+                        AddMissionEventEntity {
+                            mission_id: crew_member_ships.mission_id,
+                            actor_id: Some(crew_member_ships.brawler_id),
+                            kind: MissionEventKinds::Left,
+                            before: Some(json!({ "brawler_id": crew_member_ships.brawler_id })),
+                            after: None,
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+        .await??;
+
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::delete, insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::login_throttles::{AddLockoutAuditEntity, LoginThrottleEntity},
+        repositories::login_throttles::LoginThrottleRepository,
+        value_objects::login_throttle_policy::LoginThrottlePolicy,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{lockout_audits, login_throttles},
+    },
+};
+
+pub struct LoginThrottlePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LoginThrottlePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for LoginThrottlePostgres {
+    async fn find(&self, throttle_keys: &[String]) -> Result<Vec<LoginThrottleEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = login_throttles::table
+            .filter(login_throttles::throttle_key.eq_any(throttle_keys))
+            .select(LoginThrottleEntity::as_select())
+            .load::<LoginThrottleEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn record_failure(
+        &self,
+        throttle_key: &str,
+        policy: &LoginThrottlePolicy,
+        ip_address: Option<String>,
+    ) -> Result<LoginThrottleEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let current = login_throttles::table
+                .filter(login_throttles::throttle_key.eq(throttle_key))
+                .select(LoginThrottleEntity::as_select())
+                .for_update()
+                .first::<LoginThrottleEntity>(conn)
+                .optional()?;
+
+            let (next, locked) =
+                policy.register_failure(throttle_key, current.as_ref(), Utc::now().naive_utc());
+
+            insert_into(login_throttles::table)
+                .values(&next)
+                .on_conflict(login_throttles::throttle_key)
+                .do_update()
+                .set(&next)
+                .execute(conn)?;
+
+            if let (true, Some(locked_until)) = (locked, next.locked_until) {
+                insert_into(lockout_audits::table)
+                    .values(AddLockoutAuditEntity {
+                        throttle_key: next.throttle_key.clone(),
+                        failed_count: next.failed_count,
+                        locked_until,
+                        ip_address,
+                    })
+                    .execute(conn)?;
+            }
+
+            diesel::QueryResult::Ok(next)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn clear(&self, throttle_key: &str) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        delete(login_throttles::table)
+            .filter(login_throttles::throttle_key.eq(throttle_key))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
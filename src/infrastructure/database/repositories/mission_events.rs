@@ -0,0 +1,16 @@
+use diesel::{PgConnection, QueryResult, RunQueryDsl, insert_into};
+
+use crate::{
+    domain::entities::mission_events::AddMissionEventEntity,
+    infrastructure::database::schema::mission_events,
+};
+
+/// Appends to a mission's history. Call it inside the transaction that makes
+/// the change, so the change and its event commit or roll back together.
+pub fn record_event(conn: &mut PgConnection, event: AddMissionEventEntity) -> QueryResult<()> {
+    insert_into(mission_events::table)
+        .values(event)
+        .execute(conn)?;
+
+    Ok(())
+}
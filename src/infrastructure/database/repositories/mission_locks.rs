@@ -0,0 +1,43 @@
+use diesel::{
+    ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, QueryResult, RunQueryDsl,
+    SelectableHelper,
+};
+
+use crate::{
+    domain::entities::{mission_co_chiefs::MissionCoChiefEntity, missions::MissionEntity},
+    infrastructure::database::schema::{crew_memberships, mission_co_chiefs, missions},
+};
+
+/// Locks the live mission row until the surrounding transaction ends and
+/// counts its crew. Every crew or status change takes this lock first, so
+/// they apply one at a time per mission.
+pub fn lock_mission(conn: &mut PgConnection, mission_id: i32) -> QueryResult<(MissionEntity, i64)> {
+    let mission = missions::table
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::deleted_at.is_null())
+        .select(MissionEntity::as_select())
+        .for_update()
+        .first::<MissionEntity>(conn)?;
+
+    let crew_count = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok((mission, crew_count))
+}
+
+/// The co-chief grant `brawler_id` holds on the mission. Read after
+/// `lock_mission`, it cannot change before the transaction ends.
+pub fn find_co_chief(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    brawler_id: i32,
+) -> QueryResult<Option<MissionCoChiefEntity>> {
+    mission_co_chiefs::table
+        .filter(mission_co_chiefs::mission_id.eq(mission_id))
+        .filter(mission_co_chiefs::brawler_id.eq(brawler_id))
+        .select(MissionCoChiefEntity::as_select())
+        .first::<MissionCoChiefEntity>(conn)
+        .optional()
+}
@@ -1,14 +1,28 @@
 use crate::{
     domain::{
-        entities::missions::{AddMissionEntity, EditMissionEntity},
-        repositories::mission_management::MissionManagementRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        entities::{
+            mission_events::AddMissionEventEntity,
+            missions::{AddMissionEntity, EditMissionEntity, MissionEntity},
+        },
+        repositories::{
+            mission_management::MissionManagementRepository, mission_operation::MissionGuard,
+        },
+        value_objects::mission_event_kinds::MissionEventKinds,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{mission_events::record_event, mission_locks::lock_mission},
+        schema::{crew_memberships, mission_events, missions},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::now, dsl::update, insert_into};
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, update},
+    insert_into,
+};
 use std::sync::Arc;
 
 pub struct MissionManagementPostgres {
@@ -25,38 +39,206 @@ impl MissionManagementPostgres {
 impl MissionManagementRepository for MissionManagementPostgres {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = insert_into(missions::table)
-            .values(add_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let mission = insert_into(missions::table)
+                .values(&add_mission_entity)
+                .returning(MissionEntity::as_returning())
+                .get_result::<MissionEntity>(conn)?;
+
+            record_event(
+                conn,
+                AddMissionEventEntity {
+                    mission_id: mission.id,
+                    actor_id: Some(mission.chief_id),
+                    kind: MissionEventKinds::Created,
+                    before: None,
+                    after: Some(mission.snapshot()),
+                },
+            )?;
+
+            diesel::QueryResult::Ok(mission.id)
+        })?;
+
         Ok(result)
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_entity: EditMissionEntity,
+        guard: impl MissionGuard,
+    ) -> Result<i32> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let (before, crew_count) = lock_mission(conn, mission_id)?;
+                guard(&before, crew_count)?;
+
+                let after = update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .set(&edit_mission_entity)
+                    .returning(MissionEntity::as_returning())
+                    .get_result::<MissionEntity>(conn)?;
+
+                record_event(
+                    conn,
+                    AddMissionEventEntity {
+                        mission_id,
+                        actor_id: Some(chief_id),
+                        kind: MissionEventKinds::Edited,
+                        before: Some(before.snapshot()),
+                        after: Some(after.snapshot()),
+                    },
+                )?;
+
+                Ok(after.id)
+            })
+        })
+        .await??;
+
+        Ok(id)
+    }
+
+    async fn remove(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        removed_at: NaiveDateTime,
+        guard: impl MissionGuard,
+    ) -> Result<()> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let (before, crew_count) = lock_mission(conn, mission_id)?;
+                guard(&before, crew_count)?;
+
+                update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .set(missions::deleted_at.eq(removed_at))
+                    .execute(conn)?;
+
+                record_event(
+                    conn,
+                    AddMissionEventEntity {
+                        mission_id,
+                        actor_id: Some(chief_id),
+                        kind: MissionEventKinds::Removed,
+                        before: Some(before.snapshot()),
+                        after: None,
+                    },
+                )?;
+
+                Ok(())
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn get_trash(&self, chief_id: Option<i32>) -> Result<Vec<MissionEntity>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+
+        let mut query = missions::table
+            .filter(missions::deleted_at.is_not_null())
+            .into_boxed();
+        if let Some(chief_id) = chief_id {
+            query = query.filter(missions::chief_id.eq(chief_id));
+        }
+
+        let result = query
+            .select(MissionEntity::as_select())
+            .order_by((missions::deleted_at.desc(), missions::id.desc()))
+            .load::<MissionEntity>(&mut conn)?;
+
         Ok(result)
     }
 
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    async fn restore(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        guard: impl MissionGuard,
+    ) -> Result<i32> {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
+            let mut conn = db_pool.get()?;
+
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let before = missions::table
+                    .filter(missions::id.eq(mission_id))
+                    .filter(missions::deleted_at.is_not_null())
+                    .select(MissionEntity::as_select())
+                    .for_update()
+                    .first::<MissionEntity>(conn)?;
+                let crew_count = crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                guard(&before, crew_count)?;
+
+                let after = update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .set(missions::deleted_at.eq(None::<NaiveDateTime>))
+                    .returning(MissionEntity::as_returning())
+                    .get_result::<MissionEntity>(conn)?;
+
+                record_event(
+                    conn,
+                    AddMissionEventEntity {
+                        mission_id,
+                        actor_id: Some(actor_id),
+                        kind: MissionEventKinds::Restored,
+                        before: Some(serde_json::json!({ "deleted_at": before.deleted_at })),
+                        after: Some(after.snapshot()),
+                    },
+                )?;
+
+                Ok(after.id)
+            })
+        })
+        .await??;
+
+        Ok(id)
+    }
+
+    async fn purge_removed(&self, removed_before: NaiveDateTime) -> Result<usize> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set((
-                missions::deleted_at.eq(now),
-                missions::chief_id.eq(chief_id),
-            ))
-            .execute(&mut conn)?;
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // Locked so a restore cannot slip in between the deletes below.
+            let expired_ids = missions::table
+                .filter(missions::deleted_at.lt(removed_before))
+                .select(missions::id)
+                .for_update()
+                .load::<i32>(conn)?;
+            if expired_ids.is_empty() {
+                return diesel::QueryResult::Ok(0);
+            }
 
-        Ok(())
+            // Co-chief grants go with the crew memberships.
+            delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq_any(&expired_ids))
+                .execute(conn)?;
+            delete(mission_events::table)
+                .filter(mission_events::mission_id.eq_any(&expired_ids))
+                .execute(conn)?;
+            let purged = delete(missions::table)
+                .filter(missions::id.eq_any(&expired_ids))
+                .execute(conn)?;
+
+            diesel::QueryResult::Ok(purged)
+        })?;
+
+        Ok(result)
     }
-}
\ No newline at end of file
+}
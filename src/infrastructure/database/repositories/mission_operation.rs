@@ -2,14 +2,38 @@ use std::sync::Arc;
 
 use anyhow::{Context, Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, exists, select, update},
+    insert_into,
+    upsert::excluded,
+};
+use serde_json::{Value, json};
 
 use crate::{
     domain::{
-        repositories::mission_operation::MissionOperationRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        entities::{
+            brawlers::BrawlerEntity,
+            crew_memberships::CrewMembershipEntity,
+            mission_co_chiefs::{AddMissionCoChiefEntity, MissionCoChiefEntity},
+            mission_events::AddMissionEventEntity,
+            missions::{CancelledMissionEntity, MissionEntity},
+        },
+        errors::DomainError,
+        repositories::mission_operation::{LeadershipGuard, MissionOperationRepository},
+        value_objects::{
+            mission_event_kinds::MissionEventKinds, mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            mission_events::record_event,
+            mission_locks::{find_co_chief, lock_mission},
+        },
+        schema::{brawlers, crew_memberships, mission_co_chiefs, missions},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
 pub struct MissionOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
@@ -20,55 +44,331 @@ impl MissionOperationPostgres {
         Self { db_pool }
     }
 
-    async fn set_status(
+    /// Runs `change` in a transaction once `guard` accepts the locked mission.
+    async fn with_locked_mission<R>(
         &self,
         mission_id: i32,
-        chief_id: i32,
-        status: MissionStatuses,
-    ) -> Result<i32> {
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+        change: impl FnOnce(&mut PgConnection, &MissionEntity) -> Result<R> + Send + 'static,
+    ) -> Result<R>
+    where
+        R: Send + 'static,
+    {
         let db_pool = Arc::clone(&self.db_pool);
-        let status_string = status.to_string();
-        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
+        let result = tokio::task::spawn_blocking(move || -> Result<R> {
             let mut conn = db_pool.get().context("Failed to get DB connection")?;
 
-            update(missions::table)
-                .filter(missions::id.eq(mission_id))
-                .filter(missions::chief_id.eq(chief_id))
-                .filter(missions::deleted_at.is_null())
-                .set((missions::status.eq(status_string),))
-                .returning(missions::id)
-                .get_result::<i32>(&mut conn)
-                .context("Failed to execute mission update query")
+            conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let (mission, crew_count) = lock_mission(conn, mission_id)?;
+                let co_chief = match actor_id {
+                    Some(actor_id) => find_co_chief(conn, mission_id, actor_id)?,
+                    None => None,
+                };
+                guard(&mission, crew_count, co_chief.as_ref())?;
+
+                change(conn, &mission)
+            })
         })
         .await??;
 
-        Ok(id)
+        Ok(result)
     }
+
+    async fn set_status(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        status: MissionStatuses,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32> {
+        self.with_locked_mission(mission_id, actor_id, guard, move |conn, mission| {
+            change_status(conn, mission, actor_id, status, json!({ "status": status }))
+        })
+        .await
+    }
+}
+
+/// Removes every member of the mission's crew and returns who was released.
+pub(crate) fn release_crew(conn: &mut PgConnection, mission_id: i32) -> Result<Vec<BrawlerEntity>> {
+    let released_crew = crew_memberships::table
+        .inner_join(brawlers::table)
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .select(BrawlerEntity::as_select())
+        .load::<BrawlerEntity>(conn)?;
+
+    // Co-chief grants go with the memberships.
+    delete(crew_memberships::table)
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .execute(conn)?;
+
+    Ok(released_crew)
+}
+
+/// Applies a transition the table allows and records it, with `after`
+/// describing the new state.
+pub(crate) fn change_status(
+    conn: &mut PgConnection,
+    mission: &MissionEntity,
+    actor_id: Option<i32>,
+    status: MissionStatuses,
+    after: Value,
+) -> Result<i32> {
+    mission.status.transition(status).map_err(DomainError::from)?;
+
+    let id = update(missions::table)
+        .filter(missions::id.eq(mission.id))
+        .set((missions::status.eq(status),))
+        .returning(missions::id)
+        .get_result::<i32>(conn)
+        .context("Failed to execute mission update query")?;
+
+    record_event(
+        conn,
+        AddMissionEventEntity {
+            mission_id: mission.id,
+            actor_id,
+            kind: MissionEventKinds::StatusChanged,
+            before: Some(json!({ "status": mission.status })),
+            after: Some(after),
+        },
+    )?;
+
+    Ok(id)
 }
 
 #[async_trait]
 impl MissionOperationRepository for MissionOperationPostgres {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn to_progress(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32> {
         let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::InProgress)
+            .set_status(mission_id, actor_id, MissionStatuses::InProgress, guard)
             .await?;
 
         Ok(result)
     }
 
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn to_completed(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32> {
         let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Completed)
+            .set_status(mission_id, actor_id, MissionStatuses::Completed, guard)
             .await?;
 
         Ok(result)
     }
 
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn to_failed(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32> {
         let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Failed)
+            .set_status(mission_id, actor_id, MissionStatuses::Failed, guard)
             .await?;
 
         Ok(result)
     }
-}
\ No newline at end of file
+
+    async fn overdue_missions(&self, now: NaiveDateTime) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = missions::table
+            .filter(missions::status.eq(MissionStatuses::InProgress))
+            .filter(missions::deadline.lt(now))
+            .filter(missions::deleted_at.is_null())
+            .select(missions::id)
+            .order_by((missions::deadline.asc(), missions::id.asc()))
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn to_cancelled(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<CancelledMissionEntity> {
+        self.with_locked_mission(mission_id, actor_id, guard, move |conn, mission| {
+            let released_crew = release_crew(conn, mission_id)?;
+            let released_ids: Vec<i32> = released_crew.iter().map(|brawler| brawler.id).collect();
+            change_status(
+                conn,
+                mission,
+                actor_id,
+                MissionStatuses::Cancelled,
+                json!({ "status": MissionStatuses::Cancelled, "released_crew": released_ids }),
+            )?;
+
+            Ok(CancelledMissionEntity {
+                mission: mission.clone(),
+                released_crew,
+            })
+        })
+        .await
+    }
+
+    async fn to_archived(
+        &self,
+        mission_id: i32,
+        actor_id: Option<i32>,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32> {
+        let result = self
+            .set_status(mission_id, actor_id, MissionStatuses::Archived, guard)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        new_chief_id: i32,
+        old_chief_stays: bool,
+        guard: impl LeadershipGuard,
+    ) -> Result<i32> {
+        self.with_locked_mission(mission_id, Some(actor_id), guard, move |conn, mission| {
+            // Leaving the crew also drops any co-chief grant the new chief held.
+            let removed = delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(new_chief_id))
+                .execute(conn)?;
+            if removed == 0 {
+                return Err(DomainError::conflict(
+                    "The new chief must be a crew member of this mission",
+                )
+                .into());
+            }
+
+            let id = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set(missions::chief_id.eq(new_chief_id))
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
+
+            if old_chief_stays {
+                insert_into(crew_memberships::table)
+                    .values(CrewMembershipEntity {
+                        mission_id,
+                        brawler_id: mission.chief_id,
+                    })
+                    .execute(conn)?;
+            }
+
+            record_event(
+                conn,
+                AddMissionEventEntity {
+                    mission_id,
+                    actor_id: Some(actor_id),
+                    kind: MissionEventKinds::ChiefTransferred,
+                    before: Some(json!({ "chief_id": mission.chief_id })),
+                    after: Some(json!({
+                        "chief_id": new_chief_id,
+                        "old_chief_stays": old_chief_stays,
+                    })),
+                },
+            )?;
+
+            Ok(id)
+        })
+        .await
+    }
+
+    async fn grant_co_chief(
+        &self,
+        actor_id: i32,
+        co_chief: AddMissionCoChiefEntity,
+        guard: impl LeadershipGuard,
+    ) -> Result<MissionCoChiefEntity> {
+        let mission_id = co_chief.mission_id;
+
+        self.with_locked_mission(mission_id, Some(actor_id), guard, move |conn, _mission| {
+            let is_crew = select(exists(
+                crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(co_chief.brawler_id)),
+            ))
+            .get_result::<bool>(conn)?;
+            if !is_crew {
+                return Err(DomainError::conflict(
+                    "Co-chiefs must be crew members of this mission",
+                )
+                .into());
+            }
+
+            let granted = insert_into(mission_co_chiefs::table)
+                .values(&co_chief)
+                .on_conflict((mission_co_chiefs::mission_id, mission_co_chiefs::brawler_id))
+                .do_update()
+                .set((
+                    mission_co_chiefs::can_start.eq(excluded(mission_co_chiefs::can_start)),
+                    mission_co_chiefs::can_complete.eq(excluded(mission_co_chiefs::can_complete)),
+                    mission_co_chiefs::can_fail.eq(excluded(mission_co_chiefs::can_fail)),
+                ))
+                .returning(MissionCoChiefEntity::as_returning())
+                .get_result::<MissionCoChiefEntity>(conn)?;
+
+            record_event(
+                conn,
+                AddMissionEventEntity {
+                    mission_id,
+                    actor_id: Some(actor_id),
+                    kind: MissionEventKinds::CoChiefGranted,
+                    before: None,
+                    after: Some(json!({
+                        "brawler_id": granted.brawler_id,
+                        "can_start": granted.can_start,
+                        "can_complete": granted.can_complete,
+                        "can_fail": granted.can_fail,
+                    })),
+                },
+            )?;
+
+            Ok(granted)
+        })
+        .await
+    }
+
+    async fn revoke_co_chief(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        brawler_id: i32,
+        guard: impl LeadershipGuard,
+    ) -> Result<()> {
+        self.with_locked_mission(mission_id, Some(actor_id), guard, move |conn, _mission| {
+            let removed = delete(mission_co_chiefs::table)
+                .filter(mission_co_chiefs::mission_id.eq(mission_id))
+                .filter(mission_co_chiefs::brawler_id.eq(brawler_id))
+                .execute(conn)?;
+            if removed == 0 {
+                return Err(DomainError::not_found("Co-chief not found").into());
+            }
+
+            record_event(
+                conn,
+                AddMissionEventEntity {
+                    mission_id,
+                    actor_id: Some(actor_id),
+                    kind: MissionEventKinds::CoChiefRevoked,
+                    before: Some(json!({ "brawler_id": brawler_id })),
+                    after: None,
+                },
+            )?;
+
+            Ok(())
+        })
+        .await
+    }
+}
@@ -1,18 +1,37 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use chrono::NaiveDateTime;
+use diesel::{
+    BoolExpressionMethods, BoxableExpression, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+    dsl::{count_star, not, sql},
+    expression::SqlLiteral,
+    pg::Pg,
+    sql_types::{BigInt, Bool, Float, Nullable, Text},
+};
 
 use crate::{
     domain::{
-        entities::missions::MissionEntity,
+        entities::{
+            mission_co_chiefs::MissionCoChiefEntity,
+            mission_events::MissionEventEntity,
+            missions::{MissionEntity, MissionListingEntity},
+        },
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter},
+        value_objects::{
+            brawler_model::CrewMemberModel,
+            mission_filter::{
+                CursorValue, MissionFilter, MissionPageRequest, MissionSortKey, MissionViewer,
+                SortDirection,
+            },
+            mission_statuses::MissionStatuses,
+        },
     },
     infrastructure::database::{
         postgresql_connection::PgPoolSquad,
-        schema::{crew_memberships, missions},
+        schema::{brawlers, crew_memberships, mission_co_chiefs, mission_events, missions},
     },
 };
 pub struct MissionViewingPostgres {
@@ -30,12 +49,11 @@ impl MissionViewingRepository for MissionViewingPostgres {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let value = crew_memberships::table
+        let count = crew_memberships::table
             .filter(crew_memberships::mission_id.eq(mission_id))
             .count()
             .first::<i64>(&mut conn)?;
 
-        let count = i64::try_from(value)?;
         Ok(count)
     }
 
@@ -50,72 +68,305 @@ impl MissionViewingRepository for MissionViewingPostgres {
         Ok(result)
     }
 
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionEntity>> {
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer: &MissionViewer,
+        page: &MissionPageRequest,
+    ) -> Result<Vec<MissionListingEntity>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let mut query = missions::table
-            .filter(missions::deleted_at.is_null())
-            .into_boxed();
+        let mut query = filtered_missions(mission_filter, viewer, &page.search);
 
-        if let Some(status) = &mission_filter.status {
-            let status_string = status.to_string();
-            query = query.filter(missions::status.eq(status_string));
-        };
-        if let Some(name) = &mission_filter.name {
-            query = query.filter(missions::name.ilike(format!("%{}%", name)));
-        };
+        // Keyset pagination: rows strictly after the cursor in (sort value, id)
+        // order, so pages stay stable while missions are added.
+        macro_rules! page_by {
+            ($column:expr, $cursor_value:expr) => {{
+                if let Some(cursor) = &page.cursor {
+                    let value = $cursor_value(&cursor.value);
+                    query = match page.direction {
+                        SortDirection::Asc => query.filter(
+                            $column.gt(value.clone()).or($column
+                                .eq(value)
+                                .and(missions::id.gt(cursor.id))),
+                        ),
+                        SortDirection::Desc => query.filter(
+                            $column.lt(value.clone()).or($column
+                                .eq(value)
+                                .and(missions::id.lt(cursor.id))),
+                        ),
+                    };
+                }
+                query = match page.direction {
+                    SortDirection::Asc => query.order_by(($column.asc(), missions::id.asc())),
+                    SortDirection::Desc => query.order_by(($column.desc(), missions::id.desc())),
+                };
+            }};
+        }
+
+        match page.sort {
+            MissionSortKey::CreatedAt => page_by!(missions::created_at, timestamp_value),
+            MissionSortKey::UpdatedAt => page_by!(missions::updated_at, timestamp_value),
+            MissionSortKey::Name => page_by!(missions::name, text_value),
+            MissionSortKey::CrewCount => page_by!(crew_count(), count_value),
+            MissionSortKey::Relevance => page_by!(search_rank(&page.search), rank_value),
+        }
 
         let value = query
-            .select(MissionEntity::as_select())
-            .order_by(missions::created_at.desc())
-            .load::<MissionEntity>(&mut conn)?;
+            .select((
+                MissionEntity::as_select(),
+                crew_count(),
+                search_rank(&page.search),
+                search_snippet(&page.search),
+            ))
+            .limit(page.limit + 1)
+            .load::<(MissionEntity, i64, Option<f32>, Option<String>)>(&mut conn)?
+            .into_iter()
+            .map(
+                |(mission, crew_count, search_rank, search_snippet)| MissionListingEntity {
+                    mission,
+                    crew_count,
+                    search_rank,
+                    search_snippet,
+                },
+            )
+            .collect();
 
         Ok(value)
     }
-    async fn get_mission_count(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
+
+    async fn count_all(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer: &MissionViewer,
+        page: &MissionPageRequest,
+    ) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total = filtered_missions(mission_filter, viewer, &page.search)
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(total)
+    }
+
+    async fn get_crew(&self, mission_id: i32) -> Result<Vec<CrewMemberModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let roster = crew_memberships::table
+            .inner_join(brawlers::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .select((
+                brawlers::id,
+                brawlers::display_name,
+                brawlers::avatar_url,
+                crew_memberships::joined_at,
+            ))
+            .order_by((crew_memberships::joined_at.asc(), brawlers::id.asc()))
+            .load::<(i32, String, Option<String>, NaiveDateTime)>(&mut conn)?;
+
+        let brawler_ids: Vec<i32> = roster.iter().map(|(brawler_id, ..)| *brawler_id).collect();
+
+        // Lifetime counts for the whole roster come from two grouped queries
+        // rather than one query per member.
+        let joined_counts: HashMap<i32, i64> = crew_memberships::table
+            .inner_join(missions::table)
+            .filter(crew_memberships::brawler_id.eq_any(&brawler_ids))
+            .filter(missions::deleted_at.is_null())
+            .group_by(crew_memberships::brawler_id)
+            .select((crew_memberships::brawler_id, count_star()))
+            .load::<(i32, i64)>(&mut conn)?
+            .into_iter()
+            .collect();
+
+        let success_counts: HashMap<i32, i64> = crew_memberships::table
+            .inner_join(missions::table)
+            .filter(crew_memberships::brawler_id.eq_any(&brawler_ids))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::status.eq(MissionStatuses::Completed))
+            .group_by(crew_memberships::brawler_id)
+            .select((crew_memberships::brawler_id, count_star()))
+            .load::<(i32, i64)>(&mut conn)?
+            .into_iter()
+            .collect();
+
+        let result = roster
+            .into_iter()
+            .map(|(brawler_id, display_name, avatar_url, joined_at)| CrewMemberModel {
+                brawler_id,
+                display_name,
+                avatar_url,
+                joined_at,
+                mission_success_count: success_counts.get(&brawler_id).copied().unwrap_or(0),
+                mission_joined_count: joined_counts.get(&brawler_id).copied().unwrap_or(0),
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    async fn get_history(&self, mission_id: i32) -> Result<Vec<MissionEventEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_events::table
+            .filter(mission_events::mission_id.eq(mission_id))
+            .select(MissionEventEntity::as_select())
+            .order_by((mission_events::created_at.asc(), mission_events::id.asc()))
+            .load::<MissionEventEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_co_chiefs(&self, mission_id: i32) -> Result<Vec<MissionCoChiefEntity>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let sql = r#"
-            SELECT 
-               COALESCE(b.avatar_url, '') AS avatar_url
-               COALESCE(s.success_count, 0) AS success_count,
-               COALESCE(j.joined_count, 0) AS joined_count,
-            FROM 
-                crew_memberships cm
-            INNER JOIN 
-                brawlers b ON b.id = cm.brawler_id 
-            LEFT JOIN 
-                (
-                    SELECT 
-                        cm2.brawler_id, 
-                        COUNT(*) AS success_count
-                    FROM 
-                        crew_memberships cm2
-                    INNER JOIN 
-                        missions m2 ON m2.id = cm2.mission_id
-                    WHERE 
-                        m2.status = 'completed' AND m2.id = $1
-                    GROUP BY 
-                        cm2.brawler_id
-                ) s ON s.brawler_id = cm.brawler_id
-            LEFT JOIN 
-                (
-                    SELECT 
-                        cm3.brawler_id, 
-                        COUNT(*) AS joined_count
-                    FROM 
-                        crew_memberships cm3
-                    GROUP BY 
-                        cm3.brawler_id
-                ) j ON j.brawler_id = b.id
-            WHERE 
-                cm.mission_id = $1
-        "#;
-
-        let result = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Int4, _>(mission_id)
-            .load::<BrawlerModel>(&mut conn)?;
+        let result = mission_co_chiefs::table
+            .filter(mission_co_chiefs::mission_id.eq(mission_id))
+            .select(MissionCoChiefEntity::as_select())
+            .order_by((
+                mission_co_chiefs::granted_at.asc(),
+                mission_co_chiefs::brawler_id.asc(),
+            ))
+            .load::<MissionCoChiefEntity>(&mut conn)?;
 
         Ok(result)
     }
-}
\ No newline at end of file
+}
+
+fn filtered_missions(
+    mission_filter: &MissionFilter,
+    viewer: &MissionViewer,
+    search: &Option<String>,
+) -> missions::BoxedQuery<'static, Pg> {
+    let mut query = missions::table
+        .filter(missions::deleted_at.is_null())
+        .into_boxed();
+
+    if let Some(status) = mission_filter.status {
+        query = query.filter(missions::status.eq(status));
+    };
+    let hidden_statuses = mission_filter.hidden_statuses();
+    if !hidden_statuses.is_empty() {
+        query = query.filter(missions::status.ne_all(hidden_statuses));
+    };
+    if let Some(name) = &mission_filter.name {
+        query = query.filter(missions::name.ilike(format!("%{}%", name)));
+    };
+    if let Some(search) = search {
+        query = query.filter(
+            sql::<Bool>("missions.search_vector @@ to_tsquery('simple', ")
+                .bind::<Text, _>(search.clone())
+                .sql(")"),
+        );
+    };
+    if let Some(created_from) = mission_filter.created_from {
+        query = query.filter(missions::created_at.ge(created_from));
+    };
+    if let Some(created_to) = mission_filter.created_to {
+        query = query.filter(missions::created_at.le(created_to));
+    };
+    if mission_filter.has_free_slots.unwrap_or(false) {
+        // A free slot only counts while the crew can still be joined.
+        let joinable: Vec<MissionStatuses> = MissionStatuses::ALL
+            .into_iter()
+            .filter(|status| status.accepts_crew_changes())
+            .collect();
+        query = query
+            .filter(crew_count().lt(missions::max_crew.cast::<BigInt>()))
+            .filter(missions::status.eq_any(joinable));
+    };
+    if mission_filter.upcoming.unwrap_or(false) {
+        query = query.filter(missions::starts_at.gt(viewer.now));
+    };
+    if mission_filter.overdue.unwrap_or(false) {
+        query = query
+            .filter(missions::deadline.lt(viewer.now))
+            .filter(missions::status.eq_any([MissionStatuses::Open, MissionStatuses::InProgress]));
+    };
+
+    // The use case rejects caller-relative filters for anonymous viewers.
+    if let Some(brawler_id) = viewer.brawler_id {
+        let joined_mission_ids = crew_memberships::table
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .select(crew_memberships::mission_id);
+
+        if mission_filter.chiefed_by_me.unwrap_or(false) {
+            query = query.filter(missions::chief_id.eq(brawler_id));
+        };
+        if mission_filter.joined_by_me.unwrap_or(false) {
+            query = query.filter(missions::id.eq_any(joined_mission_ids));
+        } else if mission_filter.exclude_joined.unwrap_or(false) {
+            query = query.filter(not(missions::id.eq_any(joined_mission_ids)));
+        };
+    }
+
+    query
+}
+
+fn crew_count() -> SqlLiteral<BigInt> {
+    sql::<BigInt>(
+        "(SELECT COUNT(*) FROM crew_memberships WHERE crew_memberships.mission_id = missions.id)",
+    )
+}
+
+type MissionExpression<ST> = Box<dyn BoxableExpression<missions::table, Pg, SqlType = ST>>;
+
+fn search_rank(search: &Option<String>) -> MissionExpression<Nullable<Float>> {
+    match search {
+        Some(search) => Box::new(
+            sql::<Nullable<Float>>("ts_rank(missions.search_vector, to_tsquery('simple', ")
+                .bind::<Text, _>(search.clone())
+                .sql("))"),
+        ),
+        None => Box::new(sql::<Nullable<Float>>("NULL")),
+    }
+}
+
+/// The text is HTML-escaped before `ts_headline` sees it, so the `<mark>` tags
+/// it adds are the only markup a snippet can carry.
+fn search_snippet(search: &Option<String>) -> MissionExpression<Nullable<Text>> {
+    match search {
+        Some(search) => Box::new(
+            sql::<Nullable<Text>>(
+                "ts_headline('simple', \
+                 replace(replace(replace(replace(replace(\
+                 concat_ws(' - ', missions.name, missions.description), \
+                 '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '\"', '&quot;'), '''', '&#39;'), \
+                 to_tsquery('simple', ",
+            )
+            .bind::<Text, _>(search.clone())
+            .sql("), 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2')"),
+        ),
+        None => Box::new(sql::<Nullable<Text>>("NULL")),
+    }
+}
+
+// The cursor was checked against the sort key when it was decoded, so the
+// fallbacks below are never reached.
+fn timestamp_value(value: &CursorValue) -> NaiveDateTime {
+    match value {
+        CursorValue::Timestamp(timestamp) => *timestamp,
+        _ => NaiveDateTime::default(),
+    }
+}
+
+fn text_value(value: &CursorValue) -> String {
+    match value {
+        CursorValue::Text(text) => text.clone(),
+        _ => String::new(),
+    }
+}
+
+fn count_value(value: &CursorValue) -> i64 {
+    match value {
+        CursorValue::Count(count) => *count,
+        _ => 0,
+    }
+}
+
+fn rank_value(value: &CursorValue) -> f32 {
+    match value {
+        CursorValue::Rank(rank) => *rank,
+        _ => 0.0,
+    }
+}
@@ -1,5 +1,13 @@
+pub mod administration;
 pub mod brawlers;
 pub mod crew_operation;
+pub mod login_throttles;
+pub mod mission_events;
+pub mod mission_locks;
 pub mod mission_management;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod password_resets;
+pub mod refresh_tokens;
+pub mod sessions;
+pub mod two_factor;
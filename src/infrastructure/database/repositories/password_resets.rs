@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, RunQueryDsl, dsl::update,
+    insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::password_reset_tokens::AddPasswordResetTokenEntity,
+        repositories::password_resets::PasswordResetRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, password_reset_tokens},
+    },
+};
+
+pub struct PasswordResetPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PasswordResetPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PasswordResetPostgres {
+    async fn add(&self, add_password_reset_token_entity: AddPasswordResetTokenEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(password_reset_tokens::table)
+                .filter(password_reset_tokens::brawler_id.eq(add_password_reset_token_entity.brawler_id))
+                .filter(password_reset_tokens::used_at.is_null())
+                .set(password_reset_tokens::used_at.eq(now))
+                .execute(conn)?;
+
+            let id = insert_into(password_reset_tokens::table)
+                .values(add_password_reset_token_entity)
+                .returning(password_reset_tokens::id)
+                .get_result::<i32>(conn)?;
+
+            diesel::QueryResult::Ok(id)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn consume(&self, token_hash: &str, password: String) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // The `used_at IS NULL` guard makes a second use of the same token
+            // match nothing, even when two requests race.
+            let brawler_id = update(password_reset_tokens::table)
+                .filter(password_reset_tokens::token_hash.eq(token_hash))
+                .filter(password_reset_tokens::used_at.is_null())
+                .filter(password_reset_tokens::expires_at.gt(now))
+                .set(password_reset_tokens::used_at.eq(now))
+                .returning(password_reset_tokens::brawler_id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            if let Some(brawler_id) = brawler_id {
+                update(brawlers::table)
+                    .filter(brawlers::id.eq(brawler_id))
+                    .set(brawlers::password.eq(password))
+                    .execute(conn)?;
+            }
+
+            diesel::QueryResult::Ok(brawler_id)
+        })?;
+
+        Ok(result)
+    }
+}
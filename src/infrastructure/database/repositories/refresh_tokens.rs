@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::update, insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity},
+        repositories::refresh_tokens::RefreshTokenRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{refresh_tokens, sessions}},
+};
+
+pub struct RefreshTokenPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RefreshTokenPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenPostgres {
+    async fn find_by_hash(&self, token_hash: &str) -> Result<RefreshTokenEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = refresh_tokens::table
+            .filter(refresh_tokens::token_hash.eq(token_hash))
+            .select(RefreshTokenEntity::as_select())
+            .first::<RefreshTokenEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn rotate(
+        &self,
+        refresh_token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // Only an unrevoked token may be rotated; losing this race means the
+            // same token was presented twice.
+            let revoked = update(refresh_tokens::table)
+                .filter(refresh_tokens::id.eq(refresh_token_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(Utc::now().naive_utc()))
+                .returning(refresh_tokens::id)
+                .get_result::<i32>(conn)
+                .optional()?;
+            if revoked.is_none() {
+                return diesel::QueryResult::Ok(None);
+            }
+
+            let new_id = insert_into(refresh_tokens::table)
+                .values(&add_refresh_token_entity)
+                .returning(refresh_tokens::id)
+                .get_result::<i32>(conn)?;
+
+            update(refresh_tokens::table)
+                .filter(refresh_tokens::id.eq(refresh_token_id))
+                .set(refresh_tokens::replaced_by.eq(new_id))
+                .execute(conn)?;
+
+            update(sessions::table)
+                .filter(sessions::jti.eq(&add_refresh_token_entity.family_id))
+                .set(sessions::expires_at.eq(add_refresh_token_entity.expires_at))
+                .execute(conn)?;
+
+            diesel::QueryResult::Ok(Some(new_id))
+        })?;
+
+        Ok(result)
+    }
+}
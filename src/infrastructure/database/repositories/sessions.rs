@@ -0,0 +1,194 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::update, insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            refresh_tokens::AddRefreshTokenEntity,
+            sessions::{AddSessionEntity, SessionEntity},
+        },
+        repositories::sessions::SessionRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{refresh_tokens, sessions},
+    },
+};
+
+const REVOCATION_CACHE_TTL: Duration = Duration::from_secs(30);
+const REVOCATION_CACHE_CAPACITY: usize = 10_000;
+
+// Every authenticated request asks whether its `jti` is revoked, so answers are
+// kept in-process for a short while. Revocations made by this process update the
+// cache immediately; other instances pick them up once the entry expires.
+static REVOCATION_CACHE: LazyLock<Mutex<HashMap<String, (bool, Instant)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn cached_revocation(jti: &str) -> Option<bool> {
+    let cache = REVOCATION_CACHE.lock().ok()?;
+    cache
+        .get(jti)
+        .filter(|(_, checked_at)| checked_at.elapsed() < REVOCATION_CACHE_TTL)
+        .map(|(revoked, _)| *revoked)
+}
+
+fn cache_revocation(jti: &str, revoked: bool) {
+    if let std::result::Result::Ok(mut cache) = REVOCATION_CACHE.lock() {
+        if cache.len() >= REVOCATION_CACHE_CAPACITY {
+            cache.retain(|_, (_, checked_at)| checked_at.elapsed() < REVOCATION_CACHE_TTL);
+        }
+        cache.insert(jti.to_string(), (revoked, Instant::now()));
+    }
+}
+
+pub struct SessionPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl SessionPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+
+    fn revoke_where_jti_in(&self, jtis: &[String]) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(sessions::table)
+                .filter(sessions::jti.eq_any(jtis))
+                .filter(sessions::revoked_at.is_null())
+                .set(sessions::revoked_at.eq(now))
+                .execute(conn)?;
+
+            update(refresh_tokens::table)
+                .filter(refresh_tokens::family_id.eq_any(jtis))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(now))
+                .execute(conn)?;
+
+            diesel::QueryResult::Ok(())
+        })?;
+
+        for jti in jtis {
+            cache_revocation(jti, true);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SessionRepository for SessionPostgres {
+    async fn start(
+        &self,
+        add_session_entity: AddSessionEntity,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let session_id = insert_into(sessions::table)
+                .values(add_session_entity)
+                .returning(sessions::id)
+                .get_result::<i32>(conn)?;
+
+            insert_into(refresh_tokens::table)
+                .values(add_refresh_token_entity)
+                .execute(conn)?;
+
+            diesel::QueryResult::Ok(session_id)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn list_active(&self, brawler_id: i32) -> Result<Vec<SessionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = sessions::table
+            .filter(sessions::brawler_id.eq(brawler_id))
+            .filter(sessions::revoked_at.is_null())
+            .filter(sessions::expires_at.gt(Utc::now().naive_utc()))
+            .select(SessionEntity::as_select())
+            .order_by(sessions::created_at.desc())
+            .load::<SessionEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn is_revoked(&self, jti: &str) -> Result<bool> {
+        if let Some(revoked) = cached_revocation(jti) {
+            return Ok(revoked);
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // An unknown jti is treated as revoked.
+        let revoked = sessions::table
+            .filter(sessions::jti.eq(jti))
+            .select(sessions::revoked_at)
+            .first::<Option<chrono::NaiveDateTime>>(&mut conn)
+            .optional()?
+            .is_none_or(|revoked_at| revoked_at.is_some());
+
+        cache_revocation(jti, revoked);
+
+        Ok(revoked)
+    }
+
+    async fn revoke_by_jti(&self, jti: &str) -> Result<()> {
+        self.revoke_where_jti_in(&[jti.to_string()])
+    }
+
+    async fn revoke(&self, brawler_id: i32, session_id: i32) -> Result<()> {
+        let jti = {
+            let mut conn = Arc::clone(&self.db_pool).get()?;
+            sessions::table
+                .filter(sessions::id.eq(session_id))
+                .filter(sessions::brawler_id.eq(brawler_id))
+                .select(sessions::jti)
+                .first::<String>(&mut conn)?
+        };
+
+        self.revoke_where_jti_in(&[jti])
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()> {
+        let jtis = {
+            let mut conn = Arc::clone(&self.db_pool).get()?;
+            sessions::table
+                .filter(sessions::brawler_id.eq(brawler_id))
+                .filter(sessions::revoked_at.is_null())
+                .select(sessions::jti)
+                .load::<String>(&mut conn)?
+        };
+
+        self.revoke_where_jti_in(&jtis)
+    }
+
+    async fn revoke_others(&self, brawler_id: i32, current_jti: &str) -> Result<()> {
+        let jtis = {
+            let mut conn = Arc::clone(&self.db_pool).get()?;
+            sessions::table
+                .filter(sessions::brawler_id.eq(brawler_id))
+                .filter(sessions::revoked_at.is_null())
+                .filter(sessions::jti.ne(current_jti))
+                .select(sessions::jti)
+                .load::<String>(&mut conn)?
+        };
+
+        self.revoke_where_jti_in(&jtis)
+    }
+}
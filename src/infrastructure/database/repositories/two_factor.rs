@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper, delete, dsl::update, insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::recovery_codes::{AddRecoveryCodeEntity, RecoveryCodeEntity},
+        repositories::two_factor::TwoFactorRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, recovery_codes},
+    },
+};
+
+pub struct TwoFactorPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl TwoFactorPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn store_recovery_codes(
+    conn: &mut PgConnection,
+    brawler_id: i32,
+    recovery_code_hashes: Vec<String>,
+) -> diesel::QueryResult<()> {
+    delete(recovery_codes::table)
+        .filter(recovery_codes::brawler_id.eq(brawler_id))
+        .execute(conn)?;
+
+    let add_recovery_code_entities: Vec<AddRecoveryCodeEntity> = recovery_code_hashes
+        .into_iter()
+        .map(|code_hash| AddRecoveryCodeEntity {
+            brawler_id,
+            code_hash,
+        })
+        .collect();
+
+    insert_into(recovery_codes::table)
+        .values(&add_recovery_code_entities)
+        .execute(conn)?;
+
+    diesel::QueryResult::Ok(())
+}
+
+#[async_trait]
+impl TwoFactorRepository for TwoFactorPostgres {
+    async fn set_pending_secret(&self, brawler_id: i32, totp_secret: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .filter(brawlers::totp_enabled_at.is_null())
+            .set(brawlers::totp_secret.eq(totp_secret))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn enable(
+        &self,
+        brawler_id: i32,
+        totp_step: i64,
+        recovery_code_hashes: Vec<String>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set((
+                    brawlers::totp_enabled_at.eq(Utc::now().naive_utc()),
+                    brawlers::totp_last_used_step.eq(totp_step),
+                ))
+                .execute(conn)?;
+
+            store_recovery_codes(conn, brawler_id, recovery_code_hashes)
+        })?;
+
+        Ok(())
+    }
+
+    async fn disable(&self, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set((
+                    brawlers::totp_secret.eq(None::<String>),
+                    brawlers::totp_enabled_at.eq(None::<chrono::NaiveDateTime>),
+                    brawlers::totp_last_used_step.eq(None::<i64>),
+                ))
+                .execute(conn)?;
+
+            delete(recovery_codes::table)
+                .filter(recovery_codes::brawler_id.eq(brawler_id))
+                .execute(conn)?;
+
+            diesel::QueryResult::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        brawler_id: i32,
+        recovery_code_hashes: Vec<String>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            store_recovery_codes(conn, brawler_id, recovery_code_hashes)
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_unused_recovery_codes(&self, brawler_id: i32) -> Result<Vec<RecoveryCodeEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = recovery_codes::table
+            .filter(recovery_codes::brawler_id.eq(brawler_id))
+            .filter(recovery_codes::used_at.is_null())
+            .select(RecoveryCodeEntity::as_select())
+            .load::<RecoveryCodeEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn use_recovery_code(&self, recovery_code_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = update(recovery_codes::table)
+            .filter(recovery_codes::id.eq(recovery_code_id))
+            .filter(recovery_codes::used_at.is_null())
+            .set(recovery_codes::used_at.eq(Utc::now().naive_utc()))
+            .execute(&mut conn)?;
+
+        Ok(updated == 1)
+    }
+
+    async fn use_totp_step(&self, brawler_id: i32, totp_step: i64) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .filter(
+                brawlers::totp_last_used_step
+                    .is_null()
+                    .or(brawlers::totp_last_used_step.lt(totp_step)),
+            )
+            .set(brawlers::totp_last_used_step.eq(totp_step))
+            .execute(&mut conn)?;
+
+        Ok(updated == 1)
+    }
+}
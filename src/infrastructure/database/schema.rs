@@ -1,5 +1,11 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -15,6 +21,17 @@ diesel::table! {
         avatar_url -> Nullable<Varchar>,
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
+        #[max_length = 20]
+        role -> Varchar,
+        suspended_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        #[max_length = 64]
+        totp_secret -> Nullable<Varchar>,
+        totp_enabled_at -> Nullable<Timestamp>,
+        totp_last_used_step -> Nullable<Int8>,
+        #[max_length = 500]
+        bio -> Nullable<Varchar>,
     }
 }
 
@@ -27,6 +44,56 @@ diesel::table! {
 }
 
 diesel::table! {
+    lockout_audits (id) {
+        id -> Int4,
+        #[max_length = 320]
+        throttle_key -> Varchar,
+        failed_count -> Int4,
+        locked_until -> Timestamp,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    login_throttles (throttle_key) {
+        #[max_length = 320]
+        throttle_key -> Varchar,
+        failed_count -> Int4,
+        last_failed_at -> Timestamp,
+        locked_until -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    mission_co_chiefs (mission_id, brawler_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        can_start -> Bool,
+        can_complete -> Bool,
+        can_fail -> Bool,
+        granted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_events (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        actor_id -> Nullable<Int4>,
+        #[max_length = 32]
+        kind -> Varchar,
+        before -> Nullable<Jsonb>,
+        after -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
     missions (id) {
         id -> Int4,
         #[max_length = 255]
@@ -38,11 +105,92 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        search_vector -> Nullable<Tsvector>,
+        min_crew -> Int4,
+        max_crew -> Int4,
+        starts_at -> Nullable<Timestamp>,
+        deadline -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    password_reset_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        expires_at -> Timestamp,
+        used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    recovery_codes (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 255]
+        code_hash -> Varchar,
+        used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    refresh_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        #[max_length = 64]
+        family_id -> Varchar,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+        replaced_by -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    sessions (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 64]
+        jti -> Varchar,
+        #[max_length = 255]
+        device_name -> Nullable<Varchar>,
+        #[max_length = 512]
+        user_agent -> Nullable<Varchar>,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
     }
 }
 
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(mission_co_chiefs -> brawlers (brawler_id));
+diesel::joinable!(mission_co_chiefs -> missions (mission_id));
+diesel::joinable!(mission_events -> brawlers (actor_id));
+diesel::joinable!(mission_events -> missions (mission_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(password_reset_tokens -> brawlers (brawler_id));
+diesel::joinable!(recovery_codes -> brawlers (brawler_id));
+diesel::joinable!(refresh_tokens -> brawlers (brawler_id));
+diesel::joinable!(sessions -> brawlers (brawler_id));
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, missions,);
+diesel::allow_tables_to_appear_in_same_query!(
+    brawlers,
+    crew_memberships,
+    lockout_audits,
+    login_throttles,
+    mission_co_chiefs,
+    mission_events,
+    missions,
+    password_reset_tokens,
+    recovery_codes,
+    refresh_tokens,
+    sessions,
+);
@@ -0,0 +1,63 @@
+use axum::{
+    Json,
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::domain::errors::{DomainError, FieldError};
+
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    pub code: &'static str,
+    pub message: String,
+    pub details: Vec<FieldError>,
+}
+
+impl IntoResponse for DomainError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            DomainError::NotFound(_) => StatusCode::NOT_FOUND,
+            DomainError::Forbidden(_) => StatusCode::FORBIDDEN,
+            DomainError::Conflict(_) => StatusCode::CONFLICT,
+            DomainError::Validation { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            DomainError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            DomainError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            DomainError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let code = self.code();
+        let retry_after = match &self {
+            DomainError::RateLimited {
+                retry_after_seconds,
+                ..
+            } => HeaderValue::from_str(&retry_after_seconds.to_string()).ok(),
+            _ => None,
+        };
+        let (message, details) = match self {
+            DomainError::Validation { message, fields } => (message, fields),
+            DomainError::Internal(e) => {
+                error!("Internal error: {:?}", e);
+                ("Internal server error".to_string(), Vec::new())
+            }
+            other => (other.to_string(), Vec::new()),
+        };
+
+        let mut response = (
+            status,
+            Json(ErrorBody {
+                code,
+                message,
+                details,
+            }),
+        )
+            .into_response();
+
+        if let Some(retry_after) = retry_after {
+            response.headers_mut().insert(header::RETRY_AFTER, retry_after);
+        }
+
+        response
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::OnceLock,
+};
+
+use anyhow::Result;
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{HeaderMap, header, request::Parts},
+};
+
+use crate::{
+    config::config_loader::get_trusted_proxies,
+    domain::value_objects::session_model::SessionClientModel,
+};
+
+static TRUSTED_PROXIES: OnceLock<Vec<IpAddr>> = OnceLock::new();
+
+pub fn trusted_proxies() -> Result<&'static [IpAddr]> {
+    if let Some(trusted_proxies) = TRUSTED_PROXIES.get() {
+        return Ok(trusted_proxies);
+    }
+
+    let trusted_proxies = get_trusted_proxies()?;
+    Ok(TRUSTED_PROXIES.get_or_init(|| trusted_proxies))
+}
+
+impl<S> FromRequestParts<S> for SessionClientModel
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(512).collect());
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        // The boot check in `start` makes sure the list loads.
+        let trusted = trusted_proxies().unwrap_or(&[]);
+        let ip_address = peer.map(|peer| client_ip(peer, &parts.headers, trusted).to_string());
+
+        Ok(Self {
+            device_name: None,
+            user_agent,
+            ip_address,
+        })
+    }
+}
+
+/// `X-Forwarded-For` is only believed when the peer is a trusted proxy. The
+/// client is then the rightmost hop that is not one of our proxies, since
+/// anything to its left was written by the client itself.
+fn client_ip(peer: IpAddr, headers: &HeaderMap, trusted: &[IpAddr]) -> IpAddr {
+    if !trusted.contains(&peer) {
+        return peer;
+    }
+
+    headers
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .rsplit(',')
+                .map_while(|hop| hop.trim().parse::<IpAddr>().ok())
+                .find(|hop| !trusted.contains(hop))
+        })
+        .unwrap_or(peer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forwarded(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn header_from_an_untrusted_peer_is_ignored() {
+        let peer: IpAddr = "203.0.113.9".parse().unwrap();
+
+        let ip = client_ip(peer, &forwarded("198.51.100.1"), &[]);
+
+        assert_eq!(ip, peer);
+    }
+
+    #[test]
+    fn trusted_proxy_yields_the_rightmost_untrusted_hop() {
+        let proxy: IpAddr = "10.0.0.2".parse().unwrap();
+        let headers = forwarded("1.1.1.1, 198.51.100.1, 10.0.0.1");
+
+        let ip = client_ip(proxy, &headers, &[proxy, "10.0.0.1".parse().unwrap()]);
+
+        assert_eq!(ip, "198.51.100.1".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn garbage_header_falls_back_to_the_peer() {
+        let proxy: IpAddr = "10.0.0.2".parse().unwrap();
+
+        let ip = client_ip(proxy, &forwarded("not-an-ip"), &[proxy]);
+
+        assert_eq!(ip, proxy);
+    }
+}
@@ -19,7 +19,7 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
-    config::config_model::DotEnvyConfig, infrastructure::{database::postgresql_connection::PgPoolSquad, http::routers::{self}}
+    config::config_model::DotEnvyConfig, infrastructure::{database::postgresql_connection::PgPoolSquad, http::{extractors::client_info::trusted_proxies, routers::{self}}, jobs, jwt::keyring::keyring, mailer::build_mailer}
 };
 
 fn static_serve() -> Router {
@@ -30,28 +30,45 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn api_serve(config: &DotEnvyConfig, db_pool: Arc<PgPoolSquad>) -> Router {
     Router::new()
         .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
         .nest("/auth", routers::authentication::routes(Arc::clone(&db_pool)))
-        .nest("/mission-management", routers::missions_management::routes(Arc::clone(&db_pool)))
+        .nest("/mission-management", routers::missions_management::routes(Arc::clone(&db_pool), config.crew_bounds, config.mission_retention))
         .nest("/crew", routers::craw_operations::routes(Arc::clone(&db_pool)))
         .nest("/mission", routers::missions_operations::routes(Arc::clone(&db_pool)))
         .nest("/view", routers::missions_viewing::routes(Arc::clone(&db_pool)))
+        .nest("/password", routers::passwords::routes(Arc::clone(&db_pool)))
+        .nest("/sessions", routers::sessions::routes(Arc::clone(&db_pool)))
+        .nest("/two-factor", routers::two_factor::routes(Arc::clone(&db_pool)))
+        .nest("/admin", routers::administration::routes(Arc::clone(&db_pool)))
     .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
 
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+    // Fail at boot rather than on the first login if a key is misconfigured.
+    keyring()?;
+    trusted_proxies()?;
+
+    jobs::mission_purge::spawn(Arc::clone(&db_pool), config.mission_retention);
+    jobs::mission_expiry::spawn(
+        Arc::clone(&db_pool),
+        build_mailer()?,
+        config.scheduler.mission_expiry_interval_seconds,
+    );
+
     let app = Router::new()
         .merge(static_serve())
-        .nest("/api", api_serve(db_pool))
+        .nest("/api", api_serve(&config, db_pool))
         .route("/health_check", get(routers::default_routers::health_check))
+        .route("/.well-known/jwks.json", get(routers::default_routers::jwks))
         // .fallback(default_router::health_check)
         // .route("/health_check", get(default_router::health_check)
-        .layer(TimeoutLayer::new(Duration::from_secs(
-            config.server.timeout,
-        )))
+        .layer(TimeoutLayer::with_status_code(
+            StatusCode::REQUEST_TIMEOUT,
+            Duration::from_secs(config.server.timeout),
+        ))
         .layer(RequestBodyLimitLayer::new(
             (config.server.body_limit * 1024 * 1024).try_into()?,
         ))
@@ -74,7 +91,7 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
     let listener = TcpListener::bind(addr).await?;
 
     info!("Server start on port {}", config.server.port);
-    axum::serve(listener, app)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
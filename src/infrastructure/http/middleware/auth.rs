@@ -1,33 +1,69 @@
+use std::sync::Arc;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::sessions::SessionRepository;
 use crate::infrastructure;
-use crate::config::config_loader::get_user_secret as get_user_secret_env;
-use axum::{http::{Request, StatusCode, header}, middleware::Next, body::Body, response::Response};
-use anyhow::Result;
+use crate::infrastructure::database::{postgresql_connection::PgPoolSquad, repositories::sessions::SessionPostgres};
+use crate::infrastructure::jwt::jwt_model::Claims;
+use axum::{extract::State, http::{HeaderMap, Request, header}, middleware::Next, body::Body, response::Response};
+
+pub async fn authorization (
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Result<Response, DomainError> {
+    let (brawler_id, claims) = authenticate(db_pool, req.headers()).await?;
+
+    req.extensions_mut().insert::<i32>(brawler_id);
+    req.extensions_mut().insert(claims);
+
+    Ok(next.run(req).await)
+
+    }
 
-pub async fn authorization (mut req: Request<Body>, next: Next) -> Result<Response, StatusCode> {
-    let auth_header = req
-        .headers()
+/// Like `authorization`, but lets anonymous requests through. A token that is
+/// present must still be valid.
+pub async fn optional_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Result<Response, DomainError> {
+    if req.headers().contains_key(header::AUTHORIZATION) {
+        let (brawler_id, claims) = authenticate(db_pool, req.headers()).await?;
+
+        req.extensions_mut().insert::<i32>(brawler_id);
+        req.extensions_mut().insert(claims);
+    }
+
+    Ok(next.run(req).await)
+}
+
+async fn authenticate(
+    db_pool: Arc<PgPoolSquad>,
+    headers: &HeaderMap,
+) -> Result<(i32, Claims), DomainError> {
+    let auth_header = headers
         .get(header::AUTHORIZATION)
         .and_then(|value| value.to_str().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+        .ok_or(DomainError::unauthorized("Missing authorization header"))?;
 
     let token = auth_header
         .strip_prefix("Bearer ")
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+        .ok_or(DomainError::unauthorized("Invalid authorization scheme"))?;
 
-    let secret_env = get_user_secret_env().map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let claims =
+        infrastructure::jwt::verify_token(token.to_string())
+            .map_err(|_| DomainError::unauthorized("Invalid or expired token"))?;
 
-    let claims = 
-        infrastructure::jwt::verify_token(secret_env, token.to_string())
-            .map_err(|_| StatusCode::UNAUTHORIZED)?;
-    
     let brawler_id = claims
         .sub
         .parse::<i32>()
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
-
-    req.extensions_mut().insert::<i32>(brawler_id);
+        .map_err(|_| DomainError::unauthorized("Invalid or expired token"))?;
 
-    Ok(next.run(req).await)
-    
+    let session_repository = SessionPostgres::new(db_pool);
+    if session_repository.is_revoked(&claims.jti).await? {
+        return Err(DomainError::unauthorized("Session has been revoked"));
     }
 
+    Ok((brawler_id, claims))
+}
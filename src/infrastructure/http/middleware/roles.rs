@@ -0,0 +1,24 @@
+use axum::{body::Body, extract::State, http::Request, middleware::Next, response::Response};
+
+use crate::{
+    domain::{errors::DomainError, value_objects::brawler_roles::BrawlerRoles},
+    infrastructure::jwt::jwt_model::Claims,
+};
+
+/// Must be layered inside `authorization`, which inserts the `Claims` checked here.
+pub async fn require_roles(
+    State(roles): State<&'static [BrawlerRoles]>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, DomainError> {
+    let claims = req
+        .extensions()
+        .get::<Claims>()
+        .ok_or(DomainError::unauthorized("Missing authorization"))?;
+
+    if !roles.contains(&claims.role) {
+        return Err(DomainError::forbidden("Insufficient role for this action"));
+    }
+
+    Ok(next.run(req).await)
+}
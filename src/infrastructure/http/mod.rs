@@ -1,3 +1,5 @@
 pub mod routers;
 pub mod http_serv;
-pub mod middleware;
\ No newline at end of file
+pub mod middleware;
+pub mod error_response;
+pub mod extractors;
\ No newline at end of file
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::patch,
+};
+
+use crate::{
+    application::use_cases::administration::AdministrationUseCase,
+    domain::{
+        repositories::{administration::AdministrationRepository, sessions::SessionRepository},
+        value_objects::brawler_roles::BrawlerRoles,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{administration::AdministrationPostgres, sessions::SessionPostgres},
+        },
+        http::middleware::{auth::authorization, roles::require_roles},
+        mailer::build_mailer,
+    },
+};
+
+const ADMIN_ONLY: &[BrawlerRoles] = &[BrawlerRoles::Admin];
+const MODERATORS: &[BrawlerRoles] = &[BrawlerRoles::Moderator, BrawlerRoles::Admin];
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let administration_repository = AdministrationPostgres::new(Arc::clone(&db_pool));
+    let session_repository = SessionPostgres::new(Arc::clone(&db_pool));
+    let mailer = build_mailer().expect("Mailer settings are valid");
+    let use_case = AdministrationUseCase::new(
+        Arc::new(administration_repository),
+        Arc::new(session_repository),
+        mailer,
+    );
+
+    let moderation_router = Router::new()
+        .route("/missions/{mission_id}/force-close", patch(force_close_mission))
+        .route_layer(middleware::from_fn_with_state(MODERATORS, require_roles));
+
+    Router::new()
+        .route("/missions/{mission_id}/restore", patch(restore_mission))
+        .route("/brawlers/{brawler_id}/suspend", patch(suspend_brawler))
+        .route("/brawlers/{brawler_id}/unsuspend", patch(unsuspend_brawler))
+        .route_layer(middleware::from_fn_with_state(ADMIN_ONLY, require_roles))
+        .merge(moderation_router)
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn force_close_mission<T1, T2>(
+    State(administration_use_case): State<Arc<AdministrationUseCase<T1, T2>>>,
+    Extension(moderator_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdministrationRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match administration_use_case
+        .force_close_mission(moderator_id, mission_id)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) has been force-closed", mission_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn restore_mission<T1, T2>(
+    State(administration_use_case): State<Arc<AdministrationUseCase<T1, T2>>>,
+    Extension(admin_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdministrationRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match administration_use_case
+        .restore_mission(admin_id, mission_id)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) has been restored", mission_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn suspend_brawler<T1, T2>(
+    State(administration_use_case): State<Arc<AdministrationUseCase<T1, T2>>>,
+    Extension(admin_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdministrationRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match administration_use_case
+        .suspend_brawler(admin_id, brawler_id)
+        .await
+    {
+        Ok(brawler_id) => (
+            StatusCode::OK,
+            format!("Brawler({}) has been suspended", brawler_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn unsuspend_brawler<T1, T2>(
+    State(administration_use_case): State<Arc<AdministrationUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdministrationRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match administration_use_case.unsuspend_brawler(brawler_id).await {
+        Ok(brawler_id) => (
+            StatusCode::OK,
+            format!("Brawler({}) has been unsuspended", brawler_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
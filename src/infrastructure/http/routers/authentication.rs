@@ -2,30 +2,105 @@ use std::sync::Arc;
 
 use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
 
-use crate::{application::use_cases::authentication::AuthenticationUseCase, domain::repositories::brawlers::BrawlerRepository, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres}, jwt::authentication_model::LoginModel}};
+use crate::{config::config_loader, application::use_cases::authentication::AuthenticationUseCase, domain::{repositories::{brawlers::BrawlerRepository, login_throttles::LoginThrottleRepository, refresh_tokens::RefreshTokenRepository, sessions::SessionRepository, two_factor::TwoFactorRepository}, value_objects::{session_model::SessionClientModel, two_factor_models::MfaLoginModel}}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{brawlers::BrawlerPostgres, login_throttles::LoginThrottlePostgres, refresh_tokens::RefreshTokenPostgres, sessions::SessionPostgres, two_factor::TwoFactorPostgres}}, jwt::authentication_model::{LoginModel, RefreshTokenModel}}};
 
 
 
 
-pub async fn login<T>(
-    State(authentication_use_case): State<Arc<AuthenticationUseCase<T>>>,
+type SharedAuthenticationUseCase<T1, T2, T3, T4, T5> = Arc<AuthenticationUseCase<T1, T2, T3, T4, T5>>;
+
+pub async fn login<T1, T2, T3, T4, T5>(
+    State(authentication_use_case): State<SharedAuthenticationUseCase<T1, T2, T3, T4, T5>>,
+    session_client: SessionClientModel,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+    T5: TwoFactorRepository + Send + Sync,
+{
+    match authentication_use_case.login(login_model, session_client).await {
+        Ok(login_outcome) => (StatusCode::OK, Json(login_outcome)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn login_mfa<T1, T2, T3, T4, T5>(
+    State(authentication_use_case): State<SharedAuthenticationUseCase<T1, T2, T3, T4, T5>>,
+    session_client: SessionClientModel,
+    Json(mfa_login_model): Json<MfaLoginModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+    T5: TwoFactorRepository + Send + Sync,
+{
+    match authentication_use_case.login_mfa(mfa_login_model, session_client).await {
+        Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn refresh<T1, T2, T3, T4, T5>(
+    State(authentication_use_case): State<SharedAuthenticationUseCase<T1, T2, T3, T4, T5>>,
+    Json(refresh_token_model): Json<RefreshTokenModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+    T5: TwoFactorRepository + Send + Sync,
 {
-    match authentication_use_case.login(login_model).await {
-        Ok(passport) => (StatusCode::OK, Json(passport.access_token)).into_response(),
+    match authentication_use_case.refresh(refresh_token_model).await {
+        Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
 
-        Err(e)=> (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string())).into_response(),
+pub async fn logout<T1, T2, T3, T4, T5>(
+    State(authentication_use_case): State<SharedAuthenticationUseCase<T1, T2, T3, T4, T5>>,
+    Json(refresh_token_model): Json<RefreshTokenModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+    T5: TwoFactorRepository + Send + Sync,
+{
+    match authentication_use_case.logout(refresh_token_model).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let use_case = AuthenticationUseCase::new(Arc::new(repository));
+    let repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let refresh_token_repository = RefreshTokenPostgres::new(Arc::clone(&db_pool));
+    let session_repository = SessionPostgres::new(Arc::clone(&db_pool));
+    let login_throttle_repository = LoginThrottlePostgres::new(Arc::clone(&db_pool));
+    let two_factor_repository = TwoFactorPostgres::new(Arc::clone(&db_pool));
+    let login_throttle_policy =
+        config_loader::get_login_throttle_policy().expect("Login throttle settings are valid");
+    let use_case = AuthenticationUseCase::new(
+        Arc::new(repository),
+        Arc::new(refresh_token_repository),
+        Arc::new(session_repository),
+        Arc::new(login_throttle_repository),
+        Arc::new(two_factor_repository),
+        login_throttle_policy,
+    );
 
     Router::new()
         .route("/login", post(login))
+        .route("/login/mfa", post(login_mfa))
+        .route("/refresh", post(refresh))
+        .route("/logout", post(logout))
         .with_state(Arc::new(use_case))
-}
\ No newline at end of file
+}
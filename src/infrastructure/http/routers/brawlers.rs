@@ -1,59 +1,120 @@
 use std::sync::Arc;
 
-use axum::{ Extension, Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{ Extension, Json, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{get, post}};
 
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
     domain::{
-        repositories::brawlers::BrawlerRepository,
-        value_objects::{brawler_model::RegisterBrawlerModel, uploaded_image::UploadAvatar},
+        repositories::{brawlers::BrawlerRepository, sessions::SessionRepository},
+        value_objects::{
+            brawler_model::{EditProfileModel, RegisterBrawlerModel}, session_model::SessionClientModel,
+            uploaded_image::UploadAvatar,
+        },
     },
     infrastructure::{database::{
-        postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres,
+        postgresql_connection::PgPoolSquad,
+        repositories::{brawlers::BrawlerPostgres, sessions::SessionPostgres},
     }, http::middleware::auth::authorization},
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let brawlers_repository = BrawlerPostgres::new(db_pool);
-    let brawlers_use_case = BrawlersUseCase::new(Arc::new(brawlers_repository));
+    let brawlers_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let session_repository = SessionPostgres::new(Arc::clone(&db_pool));
+    let brawlers_use_case = BrawlersUseCase::new(
+        Arc::new(brawlers_repository),
+        Arc::new(session_repository),
+    );
 
     let protected_router = Router::new()
         .route("/avatar", post(upload_avatar))
-        .route_layer(axum::middleware::from_fn(authorization));
+        .route("/me", get(my_profile).patch(edit_profile))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization));
 
     Router::new()
         .merge(protected_router)
         .route("/register", post(register))
+        .route("/{brawler_id}", get(profile))
         .with_state(Arc::new(brawlers_use_case))
 }
 
-pub async fn register<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn register<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    session_client: SessionClientModel,
     Json(register_brawler_model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
 {
-    match brawlers_use_case.register(register_brawler_model).await {
+    match brawlers_use_case
+        .register(register_brawler_model, session_client)
+        .await {
         Ok(passport) => (StatusCode::CREATED, Json(passport)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 
-pub async fn upload_avatar<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn upload_avatar<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
     Extension(brawler_id): Extension<i32>,
     Json(upload_image): Json<UploadAvatar>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
-  {
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
     match brawlers_use_case
         .upload_avatar(upload_image.base64_string, brawler_id)
         .await
     {
         Ok(uploaded_image) => (StatusCode::CREATED, Json(uploaded_image)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn my_profile<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match brawlers_use_case.my_profile(brawler_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn edit_profile<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(edit_profile_model): Json<EditProfileModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match brawlers_use_case
+        .edit_profile(brawler_id, edit_profile_model)
+        .await
+    {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => e.into_response(),
     }
-}
\ No newline at end of file
+}
+
+pub async fn profile<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+{
+    match brawlers_use_case.profile(brawler_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -2,16 +2,15 @@ use std::sync::Arc;
 
 use axum::{Extension, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{delete, post}};
 
-use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::repositories::{crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::repositories::crew_operation::CrewOperationRepository, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::crew_operation::CrewOperationPostgres}, http::middleware::auth::authorization}};
 
-pub async fn join<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn join<T>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T: CrewOperationRepository + Send + Sync + 'static,
 {
     match crew_operation_use_case.join(mission_id, brawler_id).await {
         Ok(_) => (
@@ -19,18 +18,17 @@ where
             format!("Brawler({}) join Mission({})", brawler_id, mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn leave<T1, T2>( 
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn leave<T>( 
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T: CrewOperationRepository + Send + Sync + 'static,
 {
 
     match crew_operation_use_case.leave(mission_id, brawler_id).await {
@@ -39,24 +37,20 @@ where
             format!("Brawler({}) has leaved Mission({})", brawler_id, mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 
 }
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
 
-    let use_case = CrewOperationUseCase::new(
-        Arc::new(crew_operation_repository),
-        Arc::new(mission_viewing_repository),
-    );
+    let use_case = CrewOperationUseCase::new(Arc::new(crew_operation_repository));
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
-        .route_layer(axum::middleware::from_fn(authorization))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
         .with_state(Arc::new(use_case))
 }
 
@@ -1,5 +1,18 @@
-use axum ::{http::StatusCode, response::IntoResponse};
+use axum ::{Json, http::StatusCode, response::IntoResponse};
+use tracing::error;
+
+use crate::infrastructure::jwt::keyring::keyring;
 
 pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, "OK").into_response()
-}
\ No newline at end of file
+}
+
+pub async fn jwks() -> impl IntoResponse {
+    match keyring() {
+        Ok(keyring) => (StatusCode::OK, Json(keyring.jwks())).into_response(),
+        Err(e) => {
+            error!("Failed to load JWT keys: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -1,20 +1,19 @@
 use std::sync::Arc;
 
-use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{delete, patch, post}};
+use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{delete, get, patch, post}};
 
-use crate::{application::use_cases::mission_management::MissionManagementUseCase, domain::{repositories::{mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository}, value_objects::mission_model::{AddMissionModel, EditMissionModel}}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::mission_management::MissionManagementUseCase, domain::{repositories::mission_management::MissionManagementRepository, value_objects::{crew_bounds::CrewBounds, mission_model::{AddMissionModel, EditMissionModel}, mission_retention::MissionRetention}}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::mission_management::MissionManagementPostgres}, http::middleware::auth::authorization, jwt::jwt_model::Claims}};
 
 
 
 
-pub async fn add<T1, T2>(
-    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn add<T>(
+    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
     Json(add_mission_model): Json<AddMissionModel>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionManagementRepository + Send + Sync,
 {
     match mission_management_use_case
         .add(brawler_id, add_mission_model)
@@ -26,19 +25,18 @@ where
             });
             (StatusCode::CREATED, axum::Json(json_value)).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn edit<T1, T2>(
-    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn edit<T>(
+    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
     Json(edit_mission_model): Json<EditMissionModel>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionManagementRepository + Send + Sync,
 {
     match mission_management_use_case
         .edit(mission_id, brawler_id, edit_mission_model)
@@ -48,18 +46,17 @@ where
             let response = format!("Edit mission({}) successfully!!", mission_id);
             (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn remove<T1, T2>(
-    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn remove<T>(
+    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionManagementRepository + Send + Sync,
 {
     match mission_management_use_case
         .remove(mission_id, brawler_id)
@@ -69,24 +66,63 @@ where
             let response = format!("Remove mission({}) successfully!!!", mission_id);
             (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub async fn trash<T>(
+    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Extension(claims): Extension<Claims>,
+) -> impl IntoResponse
+where
+    T: MissionManagementRepository + Send + Sync,
+{
+    match mission_management_use_case
+        .trash(brawler_id, claims.role)
+        .await
+    {
+        Ok(trashed_mission_models) => (StatusCode::OK, Json(trashed_mission_models)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn restore<T>(
+    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionManagementRepository + Send + Sync,
+{
+    match mission_management_use_case
+        .restore(mission_id, brawler_id)
+        .await
+    {
+        Ok(mission_id) => {
+            let response = format!("Restore mission({}) successfully!!", mission_id);
+            (StatusCode::OK, response).into_response()
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, crew_bounds: CrewBounds, mission_retention: MissionRetention) -> Router {
     let mission_management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
 
     let mission_management_use_case = MissionManagementUseCase::new(
         Arc::new(mission_management_repository),
-        Arc::new(mission_viewing_repository),
+        crew_bounds,
+        mission_retention,
     );
 
     Router::new()
         .route("/", post(add))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
-        .route_layer(axum::middleware::from_fn(authorization))
+        .route("/trash", get(trash))
+        .route("/{mission_id}/restore", patch(restore))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
         // infrastructure::http::middleware::auth::authorization
         .with_state(Arc::new(mission_management_use_case))
 }
\ No newline at end of file
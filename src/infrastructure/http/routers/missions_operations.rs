@@ -1,17 +1,16 @@
 use std::sync::Arc;
 
-use axum::{Extension, Router, extract::{Path, State}, http::StatusCode, middleware, response::IntoResponse, routing::patch};
+use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, middleware, response::IntoResponse, routing::{patch, put}};
 
-use crate::{application::use_cases::mission_operation::MissionOperationUseCase, domain::{repositories::{mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository}, value_objects::mission_statuses::MissionStatuses}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::mission_operation::MissionOperationUseCase, domain::{repositories::mission_operation::MissionOperationRepository, value_objects::{mission_model::{GrantCoChiefModel, TransferChiefModel}, mission_statuses::MissionStatuses}}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::mission_operation::MissionOperationPostgres}, http::middleware::auth::authorization, mailer::build_mailer}};
 
-pub async fn in_progress<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn in_progress<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionOperationRepository + Send + Sync,
 {
     match mission_operation_use_case
         .in_progress(mission_id, chief_id)
@@ -22,18 +21,17 @@ where
             format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::InProgress),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn to_completed<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn to_completed<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionOperationRepository + Send + Sync,
 {
     match mission_operation_use_case
         .to_completed(mission_id, chief_id)
@@ -44,18 +42,17 @@ where
             format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Completed),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn to_failed<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn to_failed<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: MissionOperationRepository + Send + Sync,
 {
     match mission_operation_use_case
         .to_failed(mission_id, chief_id)
@@ -66,23 +63,128 @@ where
             format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Failed),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn to_cancelled<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionOperationRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .to_cancelled(mission_id, chief_id)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Cancelled),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn to_archived<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionOperationRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .to_archived(mission_id, chief_id)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Archived),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn transfer_chief<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(transfer): Json<TransferChiefModel>,
+) -> impl IntoResponse
+where
+    T: MissionOperationRepository + Send + Sync,
+{
+    let new_chief_id = transfer.new_chief_id;
+
+    match mission_operation_use_case
+        .transfer_chief(mission_id, chief_id, transfer)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) is now led by Brawler({})", mission_id, new_chief_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn grant_co_chief<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    Extension(chief_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    Json(grant): Json<GrantCoChiefModel>,
+) -> impl IntoResponse
+where
+    T: MissionOperationRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .grant_co_chief(mission_id, chief_id, brawler_id, grant)
+        .await
+    {
+        Ok(co_chief_model) => (StatusCode::OK, Json(co_chief_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn revoke_co_chief<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    Extension(chief_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: MissionOperationRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .revoke_co_chief(mission_id, chief_id, brawler_id)
+        .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     // หมายเหตุ: ในสไลด์มีการสะกด Misssion (s 3 ตัว) ให้ตรวจสอบ Class Name ของคุณอีกครั้ง
     let mission_operation_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let use_case = MissionOperationUseCase::new(
-        Arc::new(mission_operation_repository),
-        Arc::new(mission_viewing_repository),
-    );
+    let mailer = build_mailer().expect("Mailer settings are valid");
+    let use_case = MissionOperationUseCase::new(Arc::new(mission_operation_repository), mailer);
 
     Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
-        .route_layer(middleware::from_fn(authorization))
+        .route("/to-cancelled/{mission_id}", patch(to_cancelled))
+        .route("/to-archived/{mission_id}", patch(to_archived))
+        .route("/transfer-chief/{mission_id}", patch(transfer_chief))
+        .route(
+            "/co-chiefs/{mission_id}/{brawler_id}",
+            put(grant_co_chief).delete(revoke_co_chief),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
         .with_state(Arc::new(use_case))
 }
\ No newline at end of file
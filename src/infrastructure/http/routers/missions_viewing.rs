@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
@@ -14,19 +14,41 @@ use crate::{
         repositories::mission_viewing::MissionViewingRepository,
         value_objects::mission_filter::MissionFilter,
     },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_viewing::MissionViewingPostgres,
+        },
+        http::middleware::auth::{authorization, optional_authorization},
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_viewing_repository = MissionViewingPostgres::new(db_pool);
+    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let use_case = MissionViewingUseCase::new(Arc::new(mission_viewing_repository));
 
+    // Listing is public, but a bearer token unlocks the caller-relative filters.
+    let listing = Router::new()
+        .route("/gets", get(gets))
+        .route_layer(axum::middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            optional_authorization,
+        ));
+
+    // The audit trail names who did what, so it is for signed-in brawlers only.
+    let history = Router::new()
+        .route("/{mission_id}/history", get(get_history))
+        .route_layer(axum::middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            authorization,
+        ));
+
     Router::new()
         .route("/{mission_id}", get(view_details))
-        .route("/gets", get(gets))
-        .route("/crew/{mission_id}", get(get_mission_count))
+        .route("/crew/{mission_id}", get(get_crew))
+        .route("/co-chiefs/{mission_id}", get(get_co_chiefs))
+        .merge(listing)
+        .merge(history)
         .with_state(Arc::new(use_case))
 }
 
@@ -39,32 +61,61 @@ where
 {
     match mission_viewing_use_case.get_one(mission_id).await {
         Ok(mission_model) => (StatusCode::OK, Json(mission_model)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn gets<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    viewer: Option<Extension<i32>>,
     filter: Query<MissionFilter>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match mission_viewing_use_case.get_all(&filter).await {
-        Ok(mission_models) => (StatusCode::OK, Json(mission_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    let viewer_id = viewer.map(|Extension(brawler_id)| brawler_id);
+
+    match mission_viewing_use_case.get_all(&filter, viewer_id).await {
+        Ok(mission_page) => (StatusCode::OK, Json(mission_page)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_crew<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match mission_viewing_use_case.get_crew(mission_id).await {
+        Ok(crew_member_models) => (StatusCode::OK, Json(crew_member_models)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_history<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match mission_viewing_use_case.get_history(mission_id).await {
+        Ok(mission_event_models) => (StatusCode::OK, Json(mission_event_models)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_mission_count<T>(
+pub async fn get_co_chiefs<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match mission_viewing_use_case.get_mission_count(mission_id).await {
-        Ok(brawler_models) => (StatusCode::OK, Json(brawler_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    match mission_viewing_use_case.get_co_chiefs(mission_id).await {
+        Ok(co_chief_models) => (StatusCode::OK, Json(co_chief_models)).into_response(),
+        Err(e) => e.into_response(),
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,11 @@
 pub mod default_routers;
+pub mod administration;
 pub mod authentication;
 pub mod brawlers;
 pub mod craw_operations;
 pub mod missions_management;
 pub mod missions_operations;
 pub mod missions_viewing;
+pub mod passwords;
+pub mod sessions;
+pub mod two_factor;
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{post, put},
+};
+
+use crate::{
+    application::use_cases::passwords::PasswordsUseCase,
+    config::config_loader,
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, login_throttles::LoginThrottleRepository,
+            password_resets::PasswordResetRepository, sessions::SessionRepository,
+        },
+        value_objects::{
+            password_models::{ChangePasswordModel, ForgotPasswordModel, ResetPasswordModel},
+            session_model::SessionClientModel,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, login_throttles::LoginThrottlePostgres,
+                password_resets::PasswordResetPostgres, sessions::SessionPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+        jwt::jwt_model::Claims,
+        mailer::build_mailer,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let password_reset_repository = PasswordResetPostgres::new(Arc::clone(&db_pool));
+    let session_repository = SessionPostgres::new(Arc::clone(&db_pool));
+    let login_throttle_repository = LoginThrottlePostgres::new(Arc::clone(&db_pool));
+    let login_throttle_policy =
+        config_loader::get_login_throttle_policy().expect("Login throttle settings are valid");
+    let mailer = build_mailer().expect("Mailer settings are valid");
+    let use_case = PasswordsUseCase::new(
+        Arc::new(brawler_repository),
+        Arc::new(password_reset_repository),
+        Arc::new(session_repository),
+        Arc::new(login_throttle_repository),
+        login_throttle_policy,
+        mailer,
+    );
+
+    let protected_router = Router::new()
+        .route("/", put(change_password))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization));
+
+    Router::new()
+        .merge(protected_router)
+        .route("/forgot", post(forgot_password))
+        .route("/reset", post(reset_password))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn change_password<T1, T2, T3, T4>(
+    State(passwords_use_case): State<Arc<PasswordsUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Extension(claims): Extension<Claims>,
+    session_client: SessionClientModel,
+    Json(change_password_model): Json<ChangePasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync + 'static,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+{
+    match passwords_use_case
+        .change_password(
+            brawler_id,
+            &claims.jti,
+            session_client,
+            change_password_model,
+        )
+        .await
+    {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn forgot_password<T1, T2, T3, T4>(
+    State(passwords_use_case): State<Arc<PasswordsUseCase<T1, T2, T3, T4>>>,
+    Json(forgot_password_model): Json<ForgotPasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync + 'static,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+{
+    match passwords_use_case.forgot_password(forgot_password_model).await {
+        Ok(_) => StatusCode::ACCEPTED.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn reset_password<T1, T2, T3, T4>(
+    State(passwords_use_case): State<Arc<PasswordsUseCase<T1, T2, T3, T4>>>,
+    Json(reset_password_model): Json<ResetPasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync + 'static,
+    T3: SessionRepository + Send + Sync,
+    T4: LoginThrottleRepository + Send + Sync,
+{
+    match passwords_use_case.reset_password(reset_password_model).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
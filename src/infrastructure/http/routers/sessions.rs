@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get},
+};
+
+use crate::{
+    application::use_cases::sessions::SessionsUseCase,
+    domain::repositories::sessions::SessionRepository,
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::sessions::SessionPostgres},
+        http::middleware::auth::authorization,
+        jwt::jwt_model::Claims,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let session_repository = SessionPostgres::new(Arc::clone(&db_pool));
+    let use_case = SessionsUseCase::new(Arc::new(session_repository));
+
+    Router::new()
+        .route("/", get(list_active).delete(revoke_all))
+        .route("/{session_id}", delete(revoke))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn list_active<T>(
+    State(sessions_use_case): State<Arc<SessionsUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Extension(claims): Extension<Claims>,
+) -> impl IntoResponse
+where
+    T: SessionRepository + Send + Sync,
+{
+    match sessions_use_case.list_active(brawler_id, &claims.jti).await {
+        Ok(session_models) => (StatusCode::OK, Json(session_models)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn revoke<T>(
+    State(sessions_use_case): State<Arc<SessionsUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(session_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: SessionRepository + Send + Sync,
+{
+    match sessions_use_case.revoke(brawler_id, session_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn revoke_all<T>(
+    State(sessions_use_case): State<Arc<SessionsUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: SessionRepository + Send + Sync,
+{
+    match sessions_use_case.revoke_all(brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
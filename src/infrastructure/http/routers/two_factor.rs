@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    application::use_cases::two_factor::TwoFactorUseCase,
+    config::config_loader,
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, login_throttles::LoginThrottleRepository,
+            two_factor::TwoFactorRepository,
+        },
+        value_objects::{
+            session_model::SessionClientModel,
+            two_factor_models::{DisableTwoFactorModel, TotpCodeModel},
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, login_throttles::LoginThrottlePostgres,
+                two_factor::TwoFactorPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let two_factor_repository = TwoFactorPostgres::new(Arc::clone(&db_pool));
+    let login_throttle_repository = LoginThrottlePostgres::new(Arc::clone(&db_pool));
+    let login_throttle_policy =
+        config_loader::get_login_throttle_policy().expect("Login throttle settings are valid");
+    let use_case = TwoFactorUseCase::new(
+        Arc::new(brawler_repository),
+        Arc::new(two_factor_repository),
+        Arc::new(login_throttle_repository),
+        login_throttle_policy,
+    );
+
+    Router::new()
+        .route("/enroll", post(enroll))
+        .route("/confirm", post(confirm))
+        .route("/disable", post(disable))
+        .route("/recovery-codes", post(regenerate_recovery_codes))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
+        .with_state(Arc::new(use_case))
+}
+
+pub async fn enroll<T1, T2, T3>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: TwoFactorRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match two_factor_use_case.enroll(brawler_id).await {
+        Ok(enrollment) => (StatusCode::OK, Json(enrollment)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn confirm<T1, T2, T3>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+    session_client: SessionClientModel,
+    Json(totp_code_model): Json<TotpCodeModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: TwoFactorRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match two_factor_use_case
+        .confirm(brawler_id, totp_code_model, session_client.ip_address)
+        .await
+    {
+        Ok(recovery_codes) => (StatusCode::OK, Json(recovery_codes)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn disable<T1, T2, T3>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+    session_client: SessionClientModel,
+    Json(disable_two_factor_model): Json<DisableTwoFactorModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: TwoFactorRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match two_factor_use_case
+        .disable(
+            brawler_id,
+            disable_two_factor_model,
+            session_client.ip_address,
+        )
+        .await
+    {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn regenerate_recovery_codes<T1, T2, T3>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+    session_client: SessionClientModel,
+    Json(totp_code_model): Json<TotpCodeModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: TwoFactorRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match two_factor_use_case
+        .regenerate_recovery_codes(brawler_id, totp_code_model, session_client.ip_address)
+        .await
+    {
+        Ok(recovery_codes) => (StatusCode::OK, Json(recovery_codes)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,40 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::mission_operation::MissionOperationUseCase,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_operation::MissionOperationPostgres,
+        },
+        mailer::Mailer,
+    },
+};
+
+/// Fails overdue in-progress missions every `interval_seconds`, starting
+/// right away. A failed run is logged and retried on the next tick.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    mailer: Arc<dyn Mailer>,
+    interval_seconds: u64,
+) -> JoinHandle<()> {
+    let mission_operation_repository = MissionOperationPostgres::new(db_pool);
+    let use_case = MissionOperationUseCase::new(Arc::new(mission_operation_repository), mailer);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_seconds));
+
+        loop {
+            interval.tick().await;
+
+            match use_case.expire_overdue().await {
+                Ok(0) => {}
+                Ok(expired) => info!("Failed {} overdue missions", expired),
+                Err(e) => error!("Failed to expire overdue missions: {:?}", e),
+            }
+        }
+    })
+}
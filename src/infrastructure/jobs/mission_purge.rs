@@ -0,0 +1,37 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::mission_purge::MissionPurgeUseCase,
+    domain::value_objects::mission_retention::MissionRetention,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::mission_management::MissionManagementPostgres,
+    },
+};
+
+/// Runs the purge right away, then every `purge_interval_minutes`. A failed
+/// run is logged and retried on the next tick.
+pub fn spawn(db_pool: Arc<PgPoolSquad>, mission_retention: MissionRetention) -> JoinHandle<()> {
+    let mission_management_repository = MissionManagementPostgres::new(db_pool);
+    let use_case =
+        MissionPurgeUseCase::new(Arc::new(mission_management_repository), mission_retention);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            mission_retention.purge_interval_minutes * 60,
+        ));
+
+        loop {
+            interval.tick().await;
+
+            match use_case.purge_expired().await {
+                Ok(0) => {}
+                Ok(purged) => info!("Purged {} removed missions", purged),
+                Err(e) => error!("Failed to purge removed missions: {:?}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,2 @@
+pub mod mission_expiry;
+pub mod mission_purge;
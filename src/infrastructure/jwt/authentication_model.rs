@@ -4,4 +4,11 @@ use serde::{Deserialize, Serialize};
 pub struct LoginModel {
     pub username: String,
     pub password: String,
+    #[serde(default)]
+    pub device_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenModel {
+    pub refresh_token: String,
 }
\ No newline at end of file
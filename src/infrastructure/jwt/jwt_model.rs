@@ -2,7 +2,12 @@ use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
 
-use crate::{config::config_loader::get_jwt_env, infrastructure::jwt::generate_token};
+use crate::{
+    config::config_loader::get_jwt_env, domain::value_objects::brawler_roles::BrawlerRoles,
+    infrastructure::jwt::{decode_token, generate_token},
+};
+
+const MFA_PURPOSE: &str = "mfa";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
@@ -11,7 +16,7 @@ pub struct Passport {
     pub(crate) token_type: String,
     pub(crate) expires_in: usize,
     // pub expires_in: usize,
-    // pub(crate) refresh_token: String,
+    pub(crate) refresh_token: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,25 +24,92 @@ pub struct Claims {
     pub sub: String,
     pub exp: usize,
     pub iat: usize,
+    pub jti: String,
+    pub role: BrawlerRoles,
 }
 
-impl Passport {
+/// Proves the password step of a two-factor login. It has no `jti` or `role`,
+/// so the authorization middleware never accepts it as an access token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaClaims {
+    pub sub: String,
+    pub exp: usize,
+    pub iat: usize,
+    pub purpose: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaChallenge {
+    pub mfa_required: bool,
+    pub mfa_token: String,
+    pub expires_in: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LoginOutcome {
+    Passport(Passport),
+    MfaRequired(MfaChallenge),
+}
+
+impl MfaChallenge {
     pub fn new(brawler_id: i32) -> Result<Self> {
+        let jwt_env = get_jwt_env()?;
+        let expires_in =
+            (Utc::now() + Duration::minutes(jwt_env.mfa_lift_time_minutes)).timestamp() as usize;
+
+        let mfa_claims = MfaClaims {
+            sub: brawler_id.to_string(),
+            exp: expires_in,
+            iat: Utc::now().timestamp() as usize,
+            purpose: MFA_PURPOSE.to_string(),
+        };
+        let mfa_token = generate_token(&mfa_claims)?;
+
+        Ok(Self {
+            mfa_required: true,
+            mfa_token,
+            expires_in,
+        })
+    }
+
+    /// Returns the brawler id carried by a valid, unexpired MFA token.
+    pub fn verify(mfa_token: &str) -> Result<i32> {
+        let mfa_claims = decode_token::<MfaClaims>(mfa_token.to_string())?;
+        if mfa_claims.purpose != MFA_PURPOSE {
+            return Err(anyhow::anyhow!("Not an MFA token"));
+        }
+
+        Ok(mfa_claims.sub.parse::<i32>()?)
+    }
+}
+
+impl Passport {
+    pub fn new(
+        brawler_id: i32,
+        role: BrawlerRoles,
+        jti: String,
+        refresh_token: String,
+    ) -> Result<Self> {
         let jwt_env = get_jwt_env()?;
         let token_type = "Bearer".to_string();
-        let expires_in = (Utc::now() + Duration::days(jwt_env.lift_time_days)).timestamp() as usize;
+        let expires_in =
+            (Utc::now() + Duration::minutes(jwt_env.access_lift_time_minutes)).timestamp() as usize;
 
         let access_token_claims = Claims {
             sub: brawler_id.to_string(),
             exp: expires_in,
             iat: Utc::now().timestamp() as usize,
+            jti,
+            role,
         };
-        let access_token = generate_token(jwt_env.secret, &access_token_claims)?;
+        let access_token = generate_token(&access_token_claims)?;
 
         Ok(Self {
             token_type,
             access_token,
             expires_in,
+            refresh_token,
         })
     }
 }
\ No newline at end of file
@@ -0,0 +1,214 @@
+use std::sync::OnceLock;
+
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+};
+use rsa::{RsaPublicKey, pkcs8::DecodePublicKey, traits::PublicKeyParts};
+
+use crate::config::{
+    config_loader::get_jwt_keys,
+    config_model::{JwtKeyEnv, JwtKeyStatus},
+};
+
+pub struct SigningKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub status: JwtKeyStatus,
+    encoding_key: Option<EncodingKey>,
+    decoding_key: DecodingKey,
+    jwk: Option<Jwk>,
+}
+
+impl SigningKey {
+    fn from_env(key_env: JwtKeyEnv) -> Result<Self> {
+        let (algorithm, encoding_key, decoding_key, jwk) = match key_env.algorithm.as_str() {
+            "HS256" => {
+                let secret = key_env
+                    .secret
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("JWT key {} is missing a secret", key_env.kid))?;
+                (
+                    Algorithm::HS256,
+                    Some(EncodingKey::from_secret(secret.as_bytes())),
+                    DecodingKey::from_secret(secret.as_bytes()),
+                    // Shared secrets are never published.
+                    None,
+                )
+            }
+            "RS256" => {
+                let public_pem = read_pem(&key_env.kid, key_env.public_key_path.as_ref())?;
+                let encoding_key = key_env
+                    .private_key_path
+                    .as_ref()
+                    .map(|path| -> Result<EncodingKey> {
+                        Ok(EncodingKey::from_rsa_pem(&std::fs::read(path)?)?)
+                    })
+                    .transpose()?;
+                (
+                    Algorithm::RS256,
+                    encoding_key,
+                    DecodingKey::from_rsa_pem(public_pem.as_bytes())?,
+                    Some(rsa_jwk(&key_env.kid, &public_pem)?),
+                )
+            }
+            "EdDSA" => {
+                let public_pem = read_pem(&key_env.kid, key_env.public_key_path.as_ref())?;
+                let encoding_key = key_env
+                    .private_key_path
+                    .as_ref()
+                    .map(|path| -> Result<EncodingKey> {
+                        Ok(EncodingKey::from_ed_pem(&std::fs::read(path)?)?)
+                    })
+                    .transpose()?;
+                (
+                    Algorithm::EdDSA,
+                    encoding_key,
+                    DecodingKey::from_ed_pem(public_pem.as_bytes())?,
+                    Some(ed25519_jwk(&key_env.kid, &public_pem)?),
+                )
+            }
+            other => return Err(anyhow!("Unsupported JWT algorithm: {}", other)),
+        };
+
+        Ok(Self {
+            kid: key_env.kid,
+            algorithm,
+            status: key_env.status,
+            encoding_key,
+            decoding_key,
+            jwk,
+        })
+    }
+
+    pub fn encoding_key(&self) -> Result<&EncodingKey> {
+        self.encoding_key
+            .as_ref()
+            .ok_or_else(|| anyhow!("JWT key {} has no private key", self.kid))
+    }
+
+    pub fn decoding_key(&self) -> &DecodingKey {
+        &self.decoding_key
+    }
+}
+
+pub struct KeyRing {
+    keys: Vec<SigningKey>,
+}
+
+impl KeyRing {
+    pub fn load() -> Result<Self> {
+        let keys = get_jwt_keys()?
+            .into_iter()
+            .map(SigningKey::from_env)
+            .collect::<Result<Vec<_>>>()?;
+
+        let active_count = keys
+            .iter()
+            .filter(|key| matches!(key.status, JwtKeyStatus::Active))
+            .count();
+        if active_count != 1 {
+            return Err(anyhow!(
+                "Exactly one active JWT key is required, found {}",
+                active_count
+            ));
+        }
+
+        Ok(Self { keys })
+    }
+
+    pub fn signing_key(&self) -> Result<&SigningKey> {
+        self.keys
+            .iter()
+            .find(|key| matches!(key.status, JwtKeyStatus::Active))
+            .ok_or_else(|| anyhow!("No active JWT key"))
+    }
+
+    /// Tokens issued before key ids existed carry no `kid`; they are checked
+    /// against the `default` key.
+    pub fn verification_key(&self, kid: Option<&str>) -> Result<&SigningKey> {
+        let kid = kid.unwrap_or("default");
+
+        self.keys
+            .iter()
+            .filter(|key| !matches!(key.status, JwtKeyStatus::Retired))
+            .find(|key| key.kid == kid)
+            .ok_or_else(|| anyhow!("Unknown or retired JWT key: {}", kid))
+    }
+
+    pub fn jwks(&self) -> JwkSet {
+        let keys = self
+            .keys
+            .iter()
+            .filter(|key| !matches!(key.status, JwtKeyStatus::Retired))
+            .filter_map(|key| key.jwk.clone())
+            .collect();
+
+        JwkSet { keys }
+    }
+}
+
+static KEYRING: OnceLock<KeyRing> = OnceLock::new();
+
+pub fn keyring() -> Result<&'static KeyRing> {
+    if let Some(keyring) = KEYRING.get() {
+        return Ok(keyring);
+    }
+
+    let keyring = KeyRing::load()?;
+    Ok(KEYRING.get_or_init(|| keyring))
+}
+
+fn read_pem(kid: &str, path: Option<&String>) -> Result<String> {
+    let path = path.ok_or_else(|| anyhow!("JWT key {} is missing a public key", kid))?;
+    Ok(std::fs::read_to_string(path)?)
+}
+
+fn common_parameters(kid: &str, key_algorithm: KeyAlgorithm) -> CommonParameters {
+    CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(key_algorithm),
+        key_id: Some(kid.to_string()),
+        ..Default::default()
+    }
+}
+
+fn rsa_jwk(kid: &str, public_pem: &str) -> Result<Jwk> {
+    let public_key = RsaPublicKey::from_public_key_pem(public_pem)?;
+
+    Ok(Jwk {
+        common: common_parameters(kid, KeyAlgorithm::RS256),
+        algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n: general_purpose::URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()),
+            e: general_purpose::URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()),
+        }),
+    })
+}
+
+fn ed25519_jwk(kid: &str, public_pem: &str) -> Result<Jwk> {
+    // An Ed25519 SubjectPublicKeyInfo is a fixed 12 byte header followed by the
+    // raw 32 byte public key.
+    let body: String = public_pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    let der = general_purpose::STANDARD.decode(body)?;
+    if der.len() != 44 {
+        return Err(anyhow!("JWT key {} is not an Ed25519 public key", kid));
+    }
+
+    Ok(Jwk {
+        common: common_parameters(kid, KeyAlgorithm::EdDSA),
+        algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x: general_purpose::URL_SAFE_NO_PAD.encode(&der[12..]),
+        }),
+    })
+}
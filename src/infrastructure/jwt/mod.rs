@@ -1,23 +1,34 @@
 pub mod authentication_model;
 pub mod jwt_model;
+pub mod keyring;
+pub mod password_reset_token;
+pub mod refresh_token;
 use anyhow::Result;
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use serde::{Serialize, de::DeserializeOwned};
 
-pub fn generate_token(secret: String, claims: &jwt_model::Claims) -> Result<String> {
-    // HSA256
-    let token = encode(
-        &Header::default(),
-        claims,
-        &EncodingKey::from_secret(secret.as_ref()),
-    )?;
+pub fn generate_token<T: Serialize>(claims: &T) -> Result<String> {
+    let signing_key = keyring::keyring()?.signing_key()?;
+
+    let mut header = Header::new(signing_key.algorithm);
+    header.kid = Some(signing_key.kid.clone());
+
+    let token = encode(&header, claims, signing_key.encoding_key()?)?;
 
     Ok(token)
 }
-pub fn verify_token(secret: String, token: String) -> Result<jwt_model::Claims> {
-    let token = decode::<jwt_model::Claims>(
+pub fn verify_token(token: String) -> Result<jwt_model::Claims> {
+    decode_token::<jwt_model::Claims>(token)
+}
+
+pub fn decode_token<T: DeserializeOwned + Clone>(token: String) -> Result<T> {
+    let header = decode_header(&token)?;
+    let verification_key = keyring::keyring()?.verification_key(header.kid.as_deref())?;
+
+    let token = decode::<T>(
         &token,
-        &DecodingKey::from_secret(secret.as_ref()),
-        &Validation::default(),
+        verification_key.decoding_key(),
+        &Validation::new(verification_key.algorithm),
     )?;
 
     Ok(token.claims)
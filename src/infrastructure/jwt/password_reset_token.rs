@@ -0,0 +1,31 @@
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::{
+    config::config_loader::get_password_reset_env,
+    infrastructure::jwt::refresh_token::{hash, random_string},
+};
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetToken {
+    pub token: String,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+}
+
+impl PasswordResetToken {
+    pub fn generate() -> Result<Self> {
+        let password_reset_env = get_password_reset_env()?;
+
+        let token = random_string(32);
+        let token_hash = hash(&token);
+        let expires_at =
+            (Utc::now() + Duration::minutes(password_reset_env.lift_time_minutes)).naive_utc();
+
+        Ok(Self {
+            token,
+            token_hash,
+            expires_at,
+        })
+    }
+}
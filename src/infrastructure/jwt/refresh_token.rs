@@ -0,0 +1,46 @@
+use anyhow::Result;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::{Engine, engine::general_purpose};
+use chrono::{Duration, NaiveDateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::config::config_loader::get_jwt_env;
+
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub token: String,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+}
+
+impl RefreshToken {
+    pub fn generate() -> Result<Self> {
+        let jwt_env = get_jwt_env()?;
+
+        let token = random_string(32);
+        let token_hash = hash(&token);
+        let expires_at = (Utc::now() + Duration::days(jwt_env.lift_time_days)).naive_utc();
+
+        Ok(Self {
+            token,
+            token_hash,
+            expires_at,
+        })
+    }
+}
+
+// Refresh tokens are high-entropy random values, so a fast digest is enough to
+// keep them unusable if the table leaks.
+pub fn hash(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+pub fn generate_family_id() -> String {
+    random_string(16)
+}
+
+pub fn random_string(length: usize) -> String {
+    let mut bytes = vec![0u8; length];
+    OsRng.fill_bytes(&mut bytes);
+    general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
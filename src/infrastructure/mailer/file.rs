@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::{
+    config::config_model::MailerEnv,
+    infrastructure::mailer::{MailMessage, Mailer},
+};
+
+/// Writes each mail to its own text file so local runs can open the links.
+pub struct FileMailer {
+    from: String,
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(mailer_env: &MailerEnv) -> Self {
+        Self {
+            from: mailer_env.from.clone(),
+            dir: PathBuf::from(&mailer_env.file_dir),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, mail_message: MailMessage) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%d%H%M%S%f"),
+            mail_message.to.replace(['@', '/', '\\'], "_"),
+        ));
+        let content = format!(
+            "From: {}\nTo: {}\nSubject: {}\n\n{}\n",
+            self.from, mail_message.to, mail_message.subject, mail_message.body
+        );
+
+        tokio::fs::write(path, content).await?;
+
+        Ok(())
+    }
+}
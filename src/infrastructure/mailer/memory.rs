@@ -0,0 +1,43 @@
+use std::sync::Mutex;
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::infrastructure::mailer::{MailMessage, Mailer};
+
+/// Keeps sent mail in memory and logs it; meant for local runs and tests.
+#[derive(Default)]
+pub struct InMemoryMailer {
+    sent: Mutex<Vec<MailMessage>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sent(&self) -> Vec<MailMessage> {
+        self.sent
+            .lock()
+            .map(|sent| sent.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send(&self, mail_message: MailMessage) -> Result<()> {
+        info!(
+            "Mail to {}: {}\n{}",
+            mail_message.to, mail_message.subject, mail_message.body
+        );
+
+        self.sent
+            .lock()
+            .map_err(|_| anyhow!("Mailbox lock poisoned"))?
+            .push(mail_message);
+
+        Ok(())
+    }
+}
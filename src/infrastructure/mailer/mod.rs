@@ -0,0 +1,34 @@
+pub mod file;
+pub mod memory;
+pub mod smtp;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::config::{config_loader::get_mailer_env, config_model::MailTransport};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail_message: MailMessage) -> Result<()>;
+}
+
+pub fn build_mailer() -> Result<Arc<dyn Mailer>> {
+    let mailer_env = get_mailer_env()?;
+
+    let mailer: Arc<dyn Mailer> = match mailer_env.transport {
+        MailTransport::Smtp => Arc::new(smtp::SmtpMailer::new(&mailer_env)?),
+        MailTransport::File => Arc::new(file::FileMailer::new(&mailer_env)),
+        MailTransport::Memory => Arc::new(memory::InMemoryMailer::new()),
+    };
+
+    Ok(mailer)
+}
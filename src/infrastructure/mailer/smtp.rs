@@ -0,0 +1,55 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::{
+    config::config_model::MailerEnv,
+    infrastructure::mailer::{MailMessage, Mailer},
+};
+
+pub struct SmtpMailer {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    pub fn new(mailer_env: &MailerEnv) -> Result<Self> {
+        let host = mailer_env
+            .smtp_host
+            .as_ref()
+            .ok_or_else(|| anyhow!("SMTP_HOST not set"))?;
+
+        let mut builder =
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?.port(mailer_env.smtp_port);
+        if let (Some(username), Some(password)) =
+            (&mailer_env.smtp_username, &mailer_env.smtp_password)
+        {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            from: mailer_env.from.parse()?,
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail_message: MailMessage) -> Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(mail_message.to.parse()?)
+            .subject(mail_message.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(mail_message.body)?;
+
+        self.transport.send(message).await?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 pub mod database;
 pub mod http;
+pub mod jobs;
 pub mod argon2;
 pub mod jwt;
-pub mod cloudinary;
\ No newline at end of file
+pub mod cloudinary;
+pub mod mailer;
+pub mod totp;
@@ -0,0 +1,75 @@
+use anyhow::{Result, anyhow};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use chrono::Utc;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const DIGITS: usize = 6;
+const STEP_SECONDS: u64 = 30;
+// One step either side absorbs clock drift between the server and the phone.
+const SKEW_STEPS: i64 = 1;
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// Base32 encoded 160 bit secret, as expected by authenticator apps.
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+pub fn otpauth_uri(secret: &str, issuer: &str, account_name: &str) -> Result<String> {
+    Ok(build(secret, Some(issuer.to_string()), account_name.to_string())?.get_url())
+}
+
+/// Checks `code` against the steps around now and returns the matching step,
+/// so callers can refuse a code that was already used.
+pub fn verify(secret: &str, code: &str) -> Result<Option<i64>> {
+    let totp = build(secret, None, String::new())?;
+    let code = code.trim();
+    let current_step = Utc::now().timestamp() / STEP_SECONDS as i64;
+
+    let matched_step = (current_step - SKEW_STEPS..=current_step + SKEW_STEPS)
+        .find(|step| totp.generate((*step as u64) * STEP_SECONDS) == code);
+
+    Ok(matched_step)
+}
+
+pub fn is_totp_code(code: &str) -> bool {
+    let code = code.trim();
+    code.len() == DIGITS && code.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Codes look like `k7m2p-x9q4r`; the dash is ignored when they are checked.
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let mut bytes = [0u8; 10];
+            OsRng.fill_bytes(&mut bytes);
+            let chars: String = bytes
+                .iter()
+                .map(|byte| {
+                    RECOVERY_CODE_ALPHABET[*byte as usize % RECOVERY_CODE_ALPHABET.len()] as char
+                })
+                .collect();
+            format!("{}-{}", &chars[..5], &chars[5..])
+        })
+        .collect()
+}
+
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.trim().replace('-', "").to_lowercase()
+}
+
+fn build(secret: &str, issuer: Option<String>, account_name: String) -> Result<TOTP> {
+    let secret = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| anyhow!("Invalid TOTP secret: {:?}", e))?;
+
+    Ok(TOTP::new(
+        Algorithm::SHA1,
+        DIGITS,
+        SKEW_STEPS as u8,
+        STEP_SECONDS,
+        secret,
+        issuer,
+        account_name,
+    )?)
+}
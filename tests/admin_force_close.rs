@@ -0,0 +1,110 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{add_brawler, add_mission, database_url, join, rollback_pool};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use serde_json::Value;
+use server::{
+    application::use_cases::administration::AdministrationUseCase,
+    domain::{errors::DomainError, value_objects::mission_statuses::MissionStatuses},
+    infrastructure::{
+        database::{
+            repositories::{administration::AdministrationPostgres, sessions::SessionPostgres},
+            schema::{crew_memberships, mission_events, missions},
+        },
+        mailer::memory::InMemoryMailer,
+    },
+};
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn force_close_cancels_and_releases_the_crew() {
+    let pool = rollback_pool(database_url());
+
+    let (admin, mission_id, crew) = {
+        let mut conn = pool.get().unwrap();
+        let admin = add_brawler(&mut conn, "force_close_admin");
+        let chief = add_brawler(&mut conn, "force_close_chief");
+        let mission_id = add_mission(&mut conn, chief, MissionStatuses::InProgress, 3);
+
+        let mut crew = vec![
+            add_brawler(&mut conn, "force_close_crew_a"),
+            add_brawler(&mut conn, "force_close_crew_b"),
+        ];
+        for brawler_id in &crew {
+            join(&mut conn, mission_id, *brawler_id);
+        }
+        crew.sort();
+
+        (admin, mission_id, crew)
+    };
+
+    let use_case = AdministrationUseCase::new(
+        Arc::new(AdministrationPostgres::new(Arc::clone(&pool))),
+        Arc::new(SessionPostgres::new(Arc::clone(&pool))),
+        Arc::new(InMemoryMailer::new()),
+    );
+    assert_eq!(use_case.force_close_mission(admin, mission_id).await.unwrap(), mission_id);
+
+    let mut conn = pool.get().unwrap();
+    let status = missions::table
+        .filter(missions::id.eq(mission_id))
+        .select(missions::status)
+        .first::<MissionStatuses>(&mut conn)
+        .unwrap();
+    assert_eq!(status, MissionStatuses::Cancelled);
+
+    let remaining = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+    assert_eq!(remaining, 0);
+
+    let (actor_id, after) = mission_events::table
+        .filter(mission_events::mission_id.eq(mission_id))
+        .select((mission_events::actor_id, mission_events::after))
+        .first::<(Option<i32>, Option<Value>)>(&mut conn)
+        .unwrap();
+    assert_eq!(actor_id, Some(admin));
+    let mut released: Vec<i32> =
+        serde_json::from_value(after.unwrap()["released_crew"].clone()).unwrap();
+    released.sort();
+    assert_eq!(released, crew);
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn completed_missions_cannot_be_force_closed() {
+    let pool = rollback_pool(database_url());
+
+    let (admin, mission_id, crew_member) = {
+        let mut conn = pool.get().unwrap();
+        let admin = add_brawler(&mut conn, "force_close_admin");
+        let chief = add_brawler(&mut conn, "force_close_chief");
+        let mission_id = add_mission(&mut conn, chief, MissionStatuses::Completed, 3);
+        let crew_member = add_brawler(&mut conn, "force_close_crew");
+        join(&mut conn, mission_id, crew_member);
+
+        (admin, mission_id, crew_member)
+    };
+
+    let use_case = AdministrationUseCase::new(
+        Arc::new(AdministrationPostgres::new(Arc::clone(&pool))),
+        Arc::new(SessionPostgres::new(Arc::clone(&pool))),
+        Arc::new(InMemoryMailer::new()),
+    );
+    let result = use_case.force_close_mission(admin, mission_id).await;
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+
+    // A refused close leaves the crew where it was.
+    let mut conn = pool.get().unwrap();
+    let kept = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .filter(crew_memberships::brawler_id.eq(crew_member))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+    assert_eq!(kept, 1);
+}
@@ -0,0 +1,109 @@
+//! Helpers for the tests that run against the database in `TEST_DATABASE_URL`.
+//! Those tests are ignored by default; run them with `cargo test -- --ignored`.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use diesel::{
+    Connection, PgConnection, RunQueryDsl, insert_into,
+    r2d2::{ConnectionManager, Pool, TestCustomizer},
+};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+use server::{
+    domain::{
+        entities::{
+            brawlers::RegisterBrawlerEntity, crew_memberships::CrewMembershipEntity,
+            missions::AddMissionEntity,
+        },
+        value_objects::mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, crew_memberships, missions},
+    },
+};
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/infrastructure/database/migrations");
+
+/// The test database URL with pending migrations applied. Panics when it is
+/// not set, so an ignored test run without a database fails instead of
+/// passing.
+pub fn database_url() -> String {
+    let database_url =
+        std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL is set for database tests");
+
+    let mut conn = PgConnection::establish(&database_url).expect("test database is reachable");
+    conn.run_pending_migrations(MIGRATIONS)
+        .expect("migrations apply");
+
+    database_url
+}
+
+/// One connection that never commits: every query runs in a transaction that
+/// is rolled back, so the test database is left unchanged.
+pub fn rollback_pool(database_url: String) -> Arc<PgPoolSquad> {
+    let pool = Pool::builder()
+        .max_size(1)
+        .connection_customizer(Box::new(TestCustomizer))
+        .build(ConnectionManager::<PgConnection>::new(database_url))
+        .expect("pool builds");
+
+    Arc::new(pool)
+}
+
+/// A pool whose writes are committed, for tests that need several
+/// connections to see each other's work. Such tests clean up after
+/// themselves.
+pub fn committing_pool(database_url: String, max_size: u32) -> Arc<PgPoolSquad> {
+    let pool = Pool::builder()
+        .max_size(max_size)
+        .build(ConnectionManager::<PgConnection>::new(database_url))
+        .expect("pool builds");
+
+    Arc::new(pool)
+}
+
+pub fn add_brawler(conn: &mut PgConnection, username: &str) -> i32 {
+    insert_into(brawlers::table)
+        .values(RegisterBrawlerEntity {
+            username: username.to_string(),
+            password: "not-a-real-hash".to_string(),
+            display_name: format!("{} display", username),
+            email: None,
+        })
+        .returning(brawlers::id)
+        .get_result(conn)
+        .unwrap()
+}
+
+pub fn add_mission(
+    conn: &mut PgConnection,
+    chief_id: i32,
+    status: MissionStatuses,
+    max_crew: i32,
+) -> i32 {
+    insert_into(missions::table)
+        .values(AddMissionEntity {
+            chief_id,
+            name: "Test mission".to_string(),
+            status,
+            description: None,
+            min_crew: 1,
+            max_crew,
+            starts_at: None,
+            deadline: None,
+        })
+        .returning(missions::id)
+        .get_result(conn)
+        .unwrap()
+}
+
+pub fn join(conn: &mut PgConnection, mission_id: i32, brawler_id: i32) {
+    insert_into(crew_memberships::table)
+        .values(CrewMembershipEntity {
+            brawler_id,
+            mission_id,
+        })
+        .execute(conn)
+        .unwrap();
+}
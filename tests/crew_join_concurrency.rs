@@ -0,0 +1,94 @@
+//! Joins race against each other on real connections, so this test commits
+//! its rows and deletes them when it is done.
+
+mod common;
+
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use common::{add_brawler, add_mission, committing_pool, database_url};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, delete};
+use server::{
+    application::use_cases::crew_operation::CrewOperationUseCase,
+    domain::{errors::DomainError, value_objects::mission_statuses::MissionStatuses},
+    infrastructure::database::{
+        repositories::crew_operation::CrewOperationPostgres,
+        schema::{brawlers, crew_memberships, missions},
+    },
+};
+
+const MAX_CREW: i32 = 3;
+const CONTENDERS: usize = 24;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn concurrent_joins_never_exceed_max_crew() {
+    let pool = committing_pool(database_url(), 12);
+
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+
+    let (mission_id, brawler_ids) = {
+        let mut conn = pool.get().unwrap();
+        let chief = add_brawler(&mut conn, &format!("race_chief_{}", run));
+        let mission_id = add_mission(&mut conn, chief, MissionStatuses::Open, MAX_CREW);
+
+        let mut brawler_ids = vec![chief];
+        for contender in 0..CONTENDERS {
+            brawler_ids.push(add_brawler(&mut conn, &format!("race_{}_{}", contender, run)));
+        }
+
+        (mission_id, brawler_ids)
+    };
+
+    let use_case = Arc::new(CrewOperationUseCase::new(Arc::new(
+        CrewOperationPostgres::new(Arc::clone(&pool)),
+    )));
+
+    let joins: Vec<_> = brawler_ids[1..]
+        .iter()
+        .map(|&brawler_id| {
+            let use_case = Arc::clone(&use_case);
+            tokio::spawn(async move { use_case.join(mission_id, brawler_id).await })
+        })
+        .collect();
+
+    let mut joined = 0;
+    let mut full = 0;
+    for join in joins {
+        match join.await.unwrap() {
+            Ok(()) => joined += 1,
+            Err(DomainError::Conflict(message)) if message == "Mission is full" => full += 1,
+            Err(e) => panic!("unexpected join failure: {:?}", e),
+        }
+    }
+
+    let crew_count = {
+        let mut conn = pool.get().unwrap();
+        let crew_count = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(&mut conn)
+            .unwrap();
+
+        delete(crew_memberships::table.filter(crew_memberships::mission_id.eq(mission_id)))
+            .execute(&mut conn)
+            .unwrap();
+        delete(missions::table.filter(missions::id.eq(mission_id)))
+            .execute(&mut conn)
+            .unwrap();
+        delete(brawlers::table.filter(brawlers::id.eq_any(&brawler_ids)))
+            .execute(&mut conn)
+            .unwrap();
+
+        crew_count
+    };
+
+    assert_eq!(joined, MAX_CREW);
+    assert_eq!(full, CONTENDERS as i32 - MAX_CREW);
+    assert_eq!(crew_count, MAX_CREW as i64);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{add_brawler, add_mission, database_url, join, rollback_pool};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use server::{
+    domain::{
+        repositories::mission_viewing::MissionViewingRepository,
+        value_objects::mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+        schema::missions,
+    },
+};
+
+fn test_pool() -> Arc<PgPoolSquad> {
+    rollback_pool(database_url())
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn crew_roster_lists_members_with_lifetime_stats() {
+    let pool = test_pool();
+
+    let (mission_id, menta, ghost, chief) = {
+        let mut conn = pool.get().unwrap();
+        let conn = &mut *conn;
+
+        let chief = add_brawler(conn, "roster_chief");
+        let menta = add_brawler(conn, "roster_menta");
+        let ghost = add_brawler(conn, "roster_ghost");
+
+        let mission_id = add_mission(conn, chief, MissionStatuses::Open, 10);
+        join(conn, mission_id, menta);
+        join(conn, mission_id, ghost);
+
+        // Menta's history: one completed and one failed mission elsewhere.
+        let completed = add_mission(conn, chief, MissionStatuses::Completed, 10);
+        let failed = add_mission(conn, chief, MissionStatuses::Failed, 10);
+        join(conn, completed, menta);
+        join(conn, failed, menta);
+
+        // A deleted mission does not count.
+        let deleted = add_mission(conn, chief, MissionStatuses::Completed, 10);
+        join(conn, deleted, menta);
+        diesel::update(missions::table.filter(missions::id.eq(deleted)))
+            .set(missions::deleted_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(conn)
+            .unwrap();
+
+        (mission_id, menta, ghost, chief)
+    };
+
+    let repository = MissionViewingPostgres::new(Arc::clone(&pool));
+    let crew = repository.get_crew(mission_id).await.unwrap();
+
+    assert_eq!(crew.len(), 2);
+    assert!(crew.iter().all(|member| member.brawler_id != chief));
+
+    let menta_row = crew.iter().find(|member| member.brawler_id == menta).unwrap();
+    assert_eq!(menta_row.display_name, "roster_menta display");
+    assert_eq!(menta_row.avatar_url, None);
+    assert_eq!(menta_row.mission_joined_count, 3);
+    assert_eq!(menta_row.mission_success_count, 1);
+
+    let ghost_row = crew.iter().find(|member| member.brawler_id == ghost).unwrap();
+    assert_eq!(ghost_row.mission_joined_count, 1);
+    assert_eq!(ghost_row.mission_success_count, 0);
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn crew_roster_is_empty_for_a_mission_without_crew() {
+    let pool = test_pool();
+
+    let mission_id = {
+        let mut conn = pool.get().unwrap();
+        let chief = add_brawler(&mut conn, "roster_lonely_chief");
+        add_mission(&mut conn, chief, MissionStatuses::Open, 10)
+    };
+
+    let repository = MissionViewingPostgres::new(Arc::clone(&pool));
+    let crew = repository.get_crew(mission_id).await.unwrap();
+
+    assert!(crew.is_empty());
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use common::{add_brawler, add_mission, database_url, rollback_pool};
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, dsl::update};
+use server::{
+    application::use_cases::mission_operation::MissionOperationUseCase,
+    domain::value_objects::{
+        mission_event_kinds::MissionEventKinds, mission_statuses::MissionStatuses,
+    },
+    infrastructure::{
+        database::{
+            repositories::mission_operation::MissionOperationPostgres,
+            schema::{mission_events, missions},
+        },
+        mailer::memory::InMemoryMailer,
+    },
+};
+
+fn set_deadline(conn: &mut PgConnection, mission_id: i32, hours_from_now: i64) {
+    update(missions::table)
+        .filter(missions::id.eq(mission_id))
+        .set(missions::deadline.eq(Utc::now().naive_utc() + Duration::hours(hours_from_now)))
+        .execute(conn)
+        .unwrap();
+}
+
+fn status_of(conn: &mut PgConnection, mission_id: i32) -> MissionStatuses {
+    missions::table
+        .filter(missions::id.eq(mission_id))
+        .select(missions::status)
+        .first(conn)
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn only_overdue_in_progress_missions_fail() {
+    let pool = rollback_pool(database_url());
+
+    let (overdue, running, open_overdue) = {
+        let mut conn = pool.get().unwrap();
+        let chief = add_brawler(&mut conn, "expiry_chief");
+
+        let overdue = add_mission(&mut conn, chief, MissionStatuses::InProgress, 3);
+        set_deadline(&mut conn, overdue, -1);
+        let running = add_mission(&mut conn, chief, MissionStatuses::InProgress, 3);
+        set_deadline(&mut conn, running, 1);
+        let open_overdue = add_mission(&mut conn, chief, MissionStatuses::Open, 3);
+        set_deadline(&mut conn, open_overdue, -1);
+
+        (overdue, running, open_overdue)
+    };
+
+    let use_case = MissionOperationUseCase::new(
+        Arc::new(MissionOperationPostgres::new(Arc::clone(&pool))),
+        Arc::new(InMemoryMailer::new()),
+    );
+    let expired = use_case.expire_overdue().await.unwrap();
+
+    let mut conn = pool.get().unwrap();
+    assert!(expired >= 1);
+    assert_eq!(status_of(&mut conn, overdue), MissionStatuses::Failed);
+    assert_eq!(status_of(&mut conn, running), MissionStatuses::InProgress);
+    assert_eq!(status_of(&mut conn, open_overdue), MissionStatuses::Open);
+
+    let (kind, actor_id) = mission_events::table
+        .filter(mission_events::mission_id.eq(overdue))
+        .select((mission_events::kind, mission_events::actor_id))
+        .first::<(MissionEventKinds, Option<i32>)>(&mut conn)
+        .unwrap();
+    assert_eq!(kind, MissionEventKinds::StatusChanged);
+    assert_eq!(actor_id, None);
+}
@@ -0,0 +1,137 @@
+//! Removals race against joins on real connections, so this test commits its
+//! rows and deletes them when it is done.
+
+mod common;
+
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use common::{add_brawler, add_mission, committing_pool, database_url};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, delete};
+use server::{
+    application::use_cases::{
+        crew_operation::CrewOperationUseCase, mission_management::MissionManagementUseCase,
+    },
+    domain::{
+        errors::DomainError,
+        value_objects::{
+            crew_bounds::CrewBounds, mission_retention::MissionRetention,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        repositories::{
+            crew_operation::CrewOperationPostgres, mission_management::MissionManagementPostgres,
+        },
+        schema::{brawlers, crew_memberships, mission_events, missions},
+    },
+};
+
+const ROUNDS: usize = 16;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn a_mission_is_either_removed_or_joined() {
+    let pool = committing_pool(database_url(), 12);
+
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+
+    let (chief, brawler_id, mission_ids) = {
+        let mut conn = pool.get().unwrap();
+        let chief = add_brawler(&mut conn, &format!("remove_race_chief_{}", run));
+        let brawler_id = add_brawler(&mut conn, &format!("remove_race_brawler_{}", run));
+        let mission_ids: Vec<i32> = (0..ROUNDS)
+            .map(|_| add_mission(&mut conn, chief, MissionStatuses::Open, 3))
+            .collect();
+
+        (chief, brawler_id, mission_ids)
+    };
+
+    let management = Arc::new(MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&pool))),
+        CrewBounds {
+            min_crew: 1,
+            max_crew: 10,
+        },
+        MissionRetention {
+            restore_window_hours: 72,
+            purge_after_days: 30,
+            purge_interval_minutes: 60,
+        },
+    ));
+    let crew = Arc::new(CrewOperationUseCase::new(Arc::new(
+        CrewOperationPostgres::new(Arc::clone(&pool)),
+    )));
+
+    let races: Vec<_> = mission_ids
+        .iter()
+        .map(|&mission_id| {
+            let management = Arc::clone(&management);
+            let crew = Arc::clone(&crew);
+            let remove = tokio::spawn(async move { management.remove(mission_id, chief).await });
+            let join = tokio::spawn(async move { crew.join(mission_id, brawler_id).await });
+            (mission_id, remove, join)
+        })
+        .collect();
+
+    let mut outcomes = Vec::new();
+    for (mission_id, remove, join) in races {
+        let removed = match remove.await.unwrap() {
+            Ok(()) => true,
+            Err(DomainError::Conflict(_)) => false,
+            Err(e) => panic!("unexpected remove failure: {:?}", e),
+        };
+        let joined = match join.await.unwrap() {
+            Ok(()) => true,
+            Err(DomainError::NotFound(_)) => false,
+            Err(e) => panic!("unexpected join failure: {:?}", e),
+        };
+        outcomes.push((mission_id, removed, joined));
+    }
+
+    let crew_counts: Vec<i64> = {
+        let mut conn = pool.get().unwrap();
+        let crew_counts = mission_ids
+            .iter()
+            .map(|&mission_id| {
+                crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .count()
+                    .get_result::<i64>(&mut conn)
+                    .unwrap()
+            })
+            .collect();
+
+        delete(crew_memberships::table.filter(crew_memberships::mission_id.eq_any(&mission_ids)))
+            .execute(&mut conn)
+            .unwrap();
+        delete(mission_events::table.filter(mission_events::mission_id.eq_any(&mission_ids)))
+            .execute(&mut conn)
+            .unwrap();
+        delete(missions::table.filter(missions::id.eq_any(&mission_ids)))
+            .execute(&mut conn)
+            .unwrap();
+        delete(brawlers::table.filter(brawlers::id.eq_any([chief, brawler_id])))
+            .execute(&mut conn)
+            .unwrap();
+
+        crew_counts
+    };
+
+    for ((mission_id, removed, joined), crew_count) in outcomes.into_iter().zip(crew_counts) {
+        // Whichever takes the lock first wins; the other sees its result.
+        assert!(
+            removed != joined,
+            "mission({}) removed: {}, joined: {}",
+            mission_id,
+            removed,
+            joined
+        );
+        assert_eq!(crew_count, if joined { 1 } else { 0 });
+    }
+}
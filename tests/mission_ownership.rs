@@ -0,0 +1,186 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use common::{add_brawler, database_url, rollback_pool};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use server::{
+    application::use_cases::mission_management::MissionManagementUseCase,
+    domain::{
+        entities::missions::MissionEntity,
+        errors::DomainError,
+        value_objects::{
+            crew_bounds::CrewBounds,
+            mission_model::{AddMissionModel, EditMissionModel},
+            mission_retention::MissionRetention,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::mission_management::MissionManagementPostgres,
+        schema::missions,
+    },
+};
+
+type UseCase = MissionManagementUseCase<MissionManagementPostgres>;
+
+fn use_case(pool: &Arc<PgPoolSquad>) -> UseCase {
+    MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(pool))),
+        CrewBounds {
+            min_crew: 1,
+            max_crew: 10,
+        },
+        MissionRetention {
+            restore_window_hours: 72,
+            purge_after_days: 30,
+            purge_interval_minutes: 60,
+        },
+    )
+}
+
+fn rename(name: &str) -> EditMissionModel {
+    EditMissionModel {
+        name: Some(name.to_string()),
+        description: None,
+        min_crew: None,
+        max_crew: None,
+        starts_at: None,
+        deadline: None,
+    }
+}
+
+fn load_mission(pool: &Arc<PgPoolSquad>, mission_id: i32) -> MissionEntity {
+    let mut conn = pool.get().unwrap();
+    missions::table
+        .filter(missions::id.eq(mission_id))
+        .select(MissionEntity::as_select())
+        .first(&mut conn)
+        .unwrap()
+}
+
+async fn setup() -> (Arc<PgPoolSquad>, i32, i32, i32) {
+    let pool = rollback_pool(database_url());
+
+    let (chief, intruder) = {
+        let mut conn = pool.get().unwrap();
+        (
+            add_brawler(&mut conn, "ownership_chief"),
+            add_brawler(&mut conn, "ownership_intruder"),
+        )
+    };
+
+    let mission_id = use_case(&pool)
+        .add(
+            chief,
+            AddMissionModel {
+                name: "Guarded mission".to_string(),
+                description: None,
+                min_crew: None,
+                max_crew: None,
+                starts_at: None,
+                deadline: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    (pool, chief, intruder, mission_id)
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn another_brawler_cannot_edit_the_mission() {
+    let (pool, chief, intruder, mission_id) = setup().await;
+
+    let result = use_case(&pool)
+        .edit(mission_id, intruder, rename("Hijacked"))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::Forbidden(_))));
+    let mission = load_mission(&pool, mission_id);
+    assert_eq!(mission.name, "Guarded mission");
+    assert_eq!(mission.chief_id, chief);
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn another_brawler_cannot_remove_the_mission() {
+    let (pool, chief, intruder, mission_id) = setup().await;
+
+    let result = use_case(&pool).remove(mission_id, intruder).await;
+
+    assert!(matches!(result, Err(DomainError::Forbidden(_))));
+    let mission = load_mission(&pool, mission_id);
+    assert_eq!(mission.deleted_at, None);
+    assert_eq!(mission.chief_id, chief);
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn chief_edits_without_changing_ownership() {
+    let (pool, chief, _intruder, mission_id) = setup().await;
+
+    use_case(&pool)
+        .edit(mission_id, chief, rename("Renamed mission"))
+        .await
+        .unwrap();
+
+    let mission = load_mission(&pool, mission_id);
+    assert_eq!(mission.name, "Renamed mission");
+    assert_eq!(mission.chief_id, chief);
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn missing_or_removed_missions_are_not_found() {
+    let (pool, chief, _intruder, mission_id) = setup().await;
+
+    use_case(&pool).remove(mission_id, chief).await.unwrap();
+
+    let edit = use_case(&pool).edit(mission_id, chief, rename("Ghost")).await;
+    let remove = use_case(&pool).remove(mission_id, chief).await;
+    let missing = use_case(&pool).remove(i32::MAX, chief).await;
+
+    assert!(matches!(edit, Err(DomainError::NotFound(_))));
+    assert!(matches!(remove, Err(DomainError::NotFound(_))));
+    assert!(matches!(missing, Err(DomainError::NotFound(_))));
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn removal_is_stamped_with_the_application_clock() {
+    let (pool, chief, _intruder, mission_id) = setup().await;
+
+    let before = Utc::now().naive_utc();
+    use_case(&pool).remove(mission_id, chief).await.unwrap();
+    let after = Utc::now().naive_utc();
+
+    // The restore window and the purge compare against the same clock.
+    let deleted_at = load_mission(&pool, mission_id).deleted_at.unwrap();
+    assert!(before <= deleted_at && deleted_at <= after);
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn chief_can_clear_the_schedule() {
+    let (pool, chief, _intruder, mission_id) = setup().await;
+    let deadline = Utc::now().naive_utc() + Duration::days(2);
+
+    let schedule = |deadline| EditMissionModel {
+        deadline: Some(deadline),
+        ..rename("Scheduled mission")
+    };
+    use_case(&pool)
+        .edit(mission_id, chief, schedule(Some(deadline)))
+        .await
+        .unwrap();
+    assert!(load_mission(&pool, mission_id).deadline.is_some());
+
+    use_case(&pool)
+        .edit(mission_id, chief, schedule(None))
+        .await
+        .unwrap();
+    assert_eq!(load_mission(&pool, mission_id).deadline, None);
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{add_brawler, add_mission, database_url, rollback_pool};
+use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
+use server::{
+    application::use_cases::mission_viewing::MissionViewingUseCase,
+    domain::value_objects::{mission_filter::MissionFilter, mission_statuses::MissionStatuses},
+    infrastructure::database::{
+        repositories::mission_viewing::MissionViewingPostgres, schema::missions,
+    },
+};
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn search_snippets_escape_mission_text() {
+    let pool = rollback_pool(database_url());
+
+    let mission_id = {
+        let mut conn = pool.get().unwrap();
+        let chief = add_brawler(&mut conn, "snippet_chief");
+        let mission_id = add_mission(&mut conn, chief, MissionStatuses::Open, 3);
+        update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .set((
+                missions::name.eq("Zanzibar <script>alert('x')</script>"),
+                missions::description.eq(Some("Bring \"snacks\" & <b>gear</b>")),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+
+        mission_id
+    };
+
+    let use_case = MissionViewingUseCase::new(Arc::new(MissionViewingPostgres::new(pool)));
+    let filter = MissionFilter {
+        q: Some("zanzibar".to_string()),
+        ..MissionFilter::default()
+    };
+    let page = use_case.get_all(&filter, None).await.unwrap();
+
+    let mission = page
+        .items
+        .iter()
+        .find(|mission| mission.id == mission_id)
+        .expect("the mission matches the search");
+    let snippet = mission.search_snippet.as_deref().unwrap();
+
+    assert!(snippet.contains("<mark>Zanzibar</mark>"));
+    assert!(snippet.contains("&lt;script&gt;"));
+    // Apart from the highlights, nothing in the snippet is markup.
+    assert!(!snippet.replace("<mark>", "").replace("</mark>", "").contains('<'));
+}
+
+#[tokio::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn free_slots_only_list_joinable_missions() {
+    let pool = rollback_pool(database_url());
+
+    let (open, completed) = {
+        let mut conn = pool.get().unwrap();
+        let chief = add_brawler(&mut conn, "free_slots_chief");
+        let open = add_mission(&mut conn, chief, MissionStatuses::Open, 3);
+        let completed = add_mission(&mut conn, chief, MissionStatuses::Completed, 3);
+        update(missions::table)
+            .filter(missions::id.eq_any([open, completed]))
+            .set(missions::name.eq("Quokka patrol"))
+            .execute(&mut conn)
+            .unwrap();
+
+        (open, completed)
+    };
+
+    let use_case = MissionViewingUseCase::new(Arc::new(MissionViewingPostgres::new(pool)));
+    let filter = MissionFilter {
+        q: Some("quokka".to_string()),
+        has_free_slots: Some(true),
+        ..MissionFilter::default()
+    };
+    let page = use_case.get_all(&filter, None).await.unwrap();
+    let ids: Vec<i32> = page.items.iter().map(|mission| mission.id).collect();
+
+    assert!(ids.contains(&open));
+    // Finished missions keep their empty slots, but nobody can take them.
+    assert!(!ids.contains(&completed));
+}